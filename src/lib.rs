@@ -3,6 +3,17 @@ mod stock;
 pub mod types;
 pub(crate) mod util;
 
+pub use stock::algo::{AlgoHandle, AlgoReport, AlgoState, AlgoStrategy};
+pub use stock::batch::{BatchOutcome, BatchResult};
+pub use stock::dry_run::DryRunRequest;
+pub use stock::journal::{ExecRecord, Journal, JournalEntry};
+pub use stock::reconcile::{Discrepancy, ReconcileEvent, Reconciler};
+pub use stock::risk::{RiskEngine, RiskLimits};
+pub use stock::tracker::{OrderState, OrderTracker, TrackedOrder};
+pub use stock::trigger::{
+    PriceSource, Trail, Trigger, TriggerCondition, TriggerEngine, TriggerEvent,
+};
+
 #[macro_use]
 extern crate log;
 
@@ -14,6 +25,7 @@ pub struct KoreaInvestmentApi {
     pub order: stock::order::Korea,
     pub quote: stock::quote::Quote,
     pub k_data: stock::data::KoreaStockData,
+    pub tracker: OrderTracker,
 }
 
 impl KoreaInvestmentApi {
@@ -44,8 +56,11 @@ impl KoreaInvestmentApi {
             auth.create_approval_key().await?;
         }
         debug!("approval_key: {:?}", auth.get_approval_key());
-        let order = stock::order::Korea::new(&client, acc.clone(), auth.clone(), account.clone())?;
-        let quote = stock::quote::Quote::new(&client, acc.clone(), auth.clone(), account.clone())?;
+        let tracker = OrderTracker::new();
+        let mut order =
+            stock::order::Korea::new(&client, acc.clone(), auth.clone(), account.clone())?;
+        order.set_tracker(Some(&tracker));
+        let quote = stock::quote::Quote::new(&client, acc.clone(), auth.clone())?;
         let k_data =
             stock::data::KoreaStockData::new(acc.clone(), auth.clone(), account.clone(), hts_id)?;
         info!("API Ready");
@@ -55,6 +70,7 @@ impl KoreaInvestmentApi {
             order,
             quote,
             k_data,
+            tracker,
         })
     }
 
@@ -97,6 +113,8 @@ pub enum Error {
     #[error(transparent)]
     TomlSerializeError(#[from] toml::ser::Error),
     #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
     IoError(#[from] std::io::Error),
     // custom
     #[error("Auth init failed - None value in {0}")]
@@ -111,4 +129,18 @@ pub enum Error {
     AesInvalidLength,
     #[error("AES decrypt error: {0}")]
     AesDecryptError(String),
+    #[error("Invalid parameter - {0}: {1}")]
+    InvalidParameter(&'static str, String),
+    #[error("{1} is not supported in {0} environment")]
+    UnsupportedEnvironment(crate::types::Environment, &'static str),
+    #[error("Price out of daily limit - {0}: {1} not in [{2}, {3}]")]
+    PriceLimitExceeded(String, u32, u32, u32),
+    #[error("Rejected by risk check - {0}")]
+    RiskRejected(String),
+    #[error("Order rejected - {0}: {1}")]
+    OrderRejected(String, String),
+    #[error("Order sent but not confirmed - {0}")]
+    OrderUnconfirmed(Box<Error>),
+    #[error("{0:?} order is not accepted in {1} session")]
+    OrderClassNotInSession(crate::types::OrderClass, String),
 }
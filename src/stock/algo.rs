@@ -0,0 +1,411 @@
+use crate::stock::order::Korea;
+use crate::stock::tracker::OrderTracker;
+use crate::types::request::stock::order::OrderRequest;
+use crate::types::stream::stock::{Exec, StreamParser};
+use crate::types::Quantity;
+use crate::Error;
+use getset::{CopyGetters, Getters};
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
+use tokio::time::Instant;
+
+/// 진행 상황 점검 주기
+const TICK: Duration = Duration::from_secs(1);
+
+/// 분할 집행 방식
+#[derive(Debug, Clone)]
+pub enum AlgoStrategy {
+    /// 시간가중(TWAP): duration 동안 slices 구간으로 나눠 구간마다 같은 수량까지 제출
+    Twap { duration: Duration, slices: u32 },
+    /// 거래량 참여율(POV): 시작 이후 실시간 체결량의 participation 비율만큼 따라가며 제출
+    /// 미체결 자식 주문 잔량은 max_child_qty 이하로 유지하며, duration이 지나면
+    /// give_up이면 미체결 자식 주문을 취소하고 종료(Expired), 아니면 남은 수량을 max_child_qty씩 제출
+    Pov {
+        duration: Duration,
+        participation: f64,
+        max_child_qty: u64,
+        give_up: bool,
+    },
+    /// 빙산(iceberg): 미체결 노출 수량을 display_qty 이하로 유지하며 체결되는 만큼 이어서 제출
+    Iceberg { display_qty: u64 },
+}
+
+/// 분할 집행 상태
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlgoState {
+    Running,
+    Paused,
+    /// 전체 수량 체결
+    Completed,
+    /// 사용자 취소(미체결 자식 주문 취소)
+    Cancelled,
+    /// 자식 주문 실패로 중단(미체결 자식 주문 취소)
+    Failed(String),
+    /// 집행 시간 경과로 남은 수량 포기(미체결 자식 주문 취소)
+    Expired,
+}
+
+impl AlgoState {
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Completed | Self::Cancelled | Self::Failed(_) | Self::Expired
+        )
+    }
+}
+
+/// 분할 집행 진행 현황
+#[derive(Debug, Clone, Getters, CopyGetters)]
+pub struct AlgoReport {
+    #[getset(get = "pub")]
+    state: AlgoState,
+    /// 모주문 수량
+    #[getset(get_copy = "pub")]
+    total_qty: u64,
+    /// 제출한 자식 주문 수량 합계
+    #[getset(get_copy = "pub")]
+    sent_qty: u64,
+    #[getset(get_copy = "pub")]
+    filled_qty: u64,
+    /// 체결 평균단가
+    #[getset(get_copy = "pub")]
+    avg_price: f64,
+    /// 자식 주문번호
+    #[getset(get = "pub")]
+    children: Vec<u64>,
+    /// 시작 이후 시장 체결량(실시간 체결가 수신 시)
+    #[getset(get_copy = "pub")]
+    market_volume: u64,
+    /// 최근 체결가(실시간 체결가 수신 시)
+    #[getset(get_copy = "pub")]
+    last_price: u32,
+}
+
+enum Command {
+    Pause,
+    Resume,
+    Cancel,
+}
+
+/// 실행 중인 분할 집행 제어(drop하면 cancel과 같이 집행을 중단)
+pub struct AlgoHandle {
+    tx: mpsc::UnboundedSender<Command>,
+    report: watch::Receiver<AlgoReport>,
+}
+
+impl AlgoHandle {
+    /// 신규 자식 주문 제출 중지(제출된 주문은 유지)
+    pub fn pause(&self) {
+        let _ = self.tx.send(Command::Pause);
+    }
+
+    pub fn resume(&self) {
+        let _ = self.tx.send(Command::Resume);
+    }
+
+    /// 집행 중단 및 미체결 자식 주문 취소
+    pub fn cancel(&self) {
+        let _ = self.tx.send(Command::Cancel);
+    }
+
+    pub fn report(&self) -> AlgoReport {
+        self.report.borrow().clone()
+    }
+
+    /// 진행 현황 변경 구독
+    pub fn subscribe(&self) -> watch::Receiver<AlgoReport> {
+        self.report.clone()
+    }
+
+    /// 종료(Completed/Cancelled/Failed/Expired)될 때까지 대기
+    pub async fn wait(&self) -> AlgoReport {
+        let mut rx = self.report.clone();
+        loop {
+            {
+                let report = rx.borrow_and_update();
+                if report.state.is_terminal() {
+                    return report.clone();
+                }
+            }
+            if rx.changed().await.is_err() {
+                return rx.borrow().clone();
+            }
+        }
+    }
+}
+
+pub(crate) struct Algo {
+    korea: Korea,
+    tracker: OrderTracker,
+    parent: OrderRequest,
+    strategy: AlgoStrategy,
+    market: Option<mpsc::UnboundedReceiver<Exec>>,
+    started: Instant,
+    report: AlgoReport,
+}
+
+impl Algo {
+    pub(crate) fn start(
+        korea: Korea,
+        tracker: OrderTracker,
+        parent: OrderRequest,
+        strategy: AlgoStrategy,
+        market: Option<mpsc::UnboundedReceiver<Exec>>,
+    ) -> Result<AlgoHandle, Error> {
+        match &strategy {
+            AlgoStrategy::Twap { duration, slices } => {
+                if *slices == 0 || duration.is_zero() {
+                    return Err(Error::InvalidParameter(
+                        "Twap",
+                        "duration and slices must be greater than 0".to_string(),
+                    ));
+                }
+            }
+            AlgoStrategy::Pov {
+                participation,
+                max_child_qty,
+                ..
+            } => {
+                if !(*participation > 0.0 && *participation <= 1.0) {
+                    return Err(Error::InvalidParameter(
+                        "participation",
+                        format!("{} is not in (0, 1]", participation),
+                    ));
+                }
+                if *max_child_qty == 0 {
+                    return Err(Error::InvalidParameter(
+                        "max_child_qty",
+                        "must be greater than 0".to_string(),
+                    ));
+                }
+                if market.is_none() {
+                    return Err(Error::InvalidParameter(
+                        "market",
+                        "Pov needs the realtime Exec stream".to_string(),
+                    ));
+                }
+            }
+            AlgoStrategy::Iceberg { display_qty } => {
+                if *display_qty == 0 {
+                    return Err(Error::InvalidParameter(
+                        "display_qty",
+                        "must be greater than 0".to_string(),
+                    ));
+                }
+            }
+        }
+        let report = AlgoReport {
+            state: AlgoState::Running,
+            total_qty: parent.qty().inner() as u64,
+            sent_qty: 0,
+            filled_qty: 0,
+            avg_price: 0.0,
+            children: vec![],
+            market_volume: 0,
+            last_price: 0,
+        };
+        let (tx, rx) = mpsc::unbounded_channel();
+        let (report_tx, report_rx) = watch::channel(report.clone());
+        let algo = Self {
+            korea,
+            tracker,
+            parent,
+            strategy,
+            market,
+            started: Instant::now(),
+            report,
+        };
+        tokio::spawn(algo.run(rx, report_tx));
+        Ok(AlgoHandle {
+            tx,
+            report: report_rx,
+        })
+    }
+
+    async fn run(
+        mut self,
+        mut rx: mpsc::UnboundedReceiver<Command>,
+        report_tx: watch::Sender<AlgoReport>,
+    ) {
+        let mut tick = tokio::time::interval(TICK);
+        loop {
+            tokio::select! {
+                command = rx.recv() => match command {
+                    Some(Command::Pause) => self.report.state = AlgoState::Paused,
+                    Some(Command::Resume) => self.report.state = AlgoState::Running,
+                    Some(Command::Cancel) | None => {
+                        self.cancel_children().await;
+                        self.report.state = AlgoState::Cancelled;
+                    }
+                },
+                exec = recv_market(&mut self.market) => match exec {
+                    Some(exec) => self.on_market(&exec),
+                    None => self.market = None,
+                },
+                _ = tick.tick() => {}
+            }
+            self.refresh();
+            if !self.report.state.is_terminal() {
+                if self.report.filled_qty >= self.report.total_qty {
+                    self.report.state = AlgoState::Completed;
+                } else if self.expired() {
+                    self.cancel_children().await;
+                    self.refresh();
+                    self.report.state = AlgoState::Expired;
+                } else if let AlgoState::Running = self.report.state {
+                    if let Err(e) = self.step().await {
+                        warn!("Algo for {} failed: {}", self.parent.pdno(), e);
+                        self.cancel_children().await;
+                        self.refresh();
+                        self.report.state = AlgoState::Failed(e.to_string());
+                    }
+                }
+            }
+            report_tx.send_replace(self.report.clone());
+            if self.report.state.is_terminal() {
+                return;
+            }
+        }
+    }
+
+    fn on_market(&mut self, exec: &Exec) {
+        if let Some(body) = exec.body() {
+            if &body.shortcode == self.parent.pdno() {
+                self.report.market_volume += body.exec_volume;
+                self.report.last_price = body.current_price;
+            }
+        }
+    }
+
+    /// tracker에서 자식 주문 체결 현황 반영
+    fn refresh(&mut self) {
+        let (mut filled, mut amount) = (0, 0.0);
+        for order in self
+            .report
+            .children
+            .iter()
+            .filter_map(|no| self.tracker.get(*no))
+        {
+            filled += order.filled_qty();
+            amount += order.filled_qty() as f64 * order.avg_price();
+        }
+        self.report.filled_qty = filled;
+        self.report.avg_price = if filled > 0 {
+            amount / filled as f64
+        } else {
+            0.0
+        };
+    }
+
+    /// 미체결 자식 주문 잔량 합계
+    fn open_qty(&self) -> u64 {
+        self.report
+            .children
+            .iter()
+            .filter_map(|no| self.tracker.get(*no))
+            .filter(|order| !order.state().is_terminal())
+            .map(|order| order.remaining_qty())
+            .sum()
+    }
+
+    /// 집행 시간이 지나 남은 수량을 포기해야 하는지
+    fn expired(&self) -> bool {
+        match &self.strategy {
+            AlgoStrategy::Pov {
+                duration, give_up, ..
+            } => *give_up && self.started.elapsed() >= *duration,
+            _ => false,
+        }
+    }
+
+    /// 현재까지 제출되어 있어야 할 누적 수량
+    fn target_qty(&self) -> u64 {
+        let total = self.report.total_qty;
+        let elapsed = self.started.elapsed();
+        match &self.strategy {
+            AlgoStrategy::Twap { duration, slices } => {
+                let slices = *slices as u64;
+                let slice =
+                    (elapsed.as_secs_f64() / duration.as_secs_f64() * slices as f64) as u64 + 1;
+                total * slice.min(slices) / slices
+            }
+            AlgoStrategy::Pov {
+                duration,
+                participation,
+                ..
+            } => {
+                if elapsed >= *duration {
+                    total
+                } else {
+                    ((self.report.market_volume as f64 * participation) as u64).min(total)
+                }
+            }
+            AlgoStrategy::Iceberg { .. } => total,
+        }
+    }
+
+    /// 목표 수량에 못 미치는 만큼 자식 주문 제출
+    async fn step(&mut self) -> Result<(), Error> {
+        let open = self.open_qty();
+        let mut qty = self
+            .target_qty()
+            .saturating_sub(self.report.filled_qty + open);
+        match &self.strategy {
+            AlgoStrategy::Iceberg { display_qty } => {
+                qty = qty.min(display_qty.saturating_sub(open));
+            }
+            AlgoStrategy::Pov { max_child_qty, .. } => {
+                qty = qty.min(max_child_qty.saturating_sub(open));
+            }
+            AlgoStrategy::Twap { .. } => {}
+        }
+        if qty == 0 {
+            return Ok(());
+        }
+        let child_qty = u32::try_from(qty).map_err(|_| {
+            Error::InvalidParameter("ord_qty", format!("child quantity {} is out of range", qty))
+        })?;
+        let mut builder =
+            OrderRequest::builder(self.parent.direction().clone(), self.parent.pdno())
+                .order_class(self.parent.order_class().clone())
+                .qty(Quantity::from(child_qty))
+                .price(*self.parent.price());
+        if let Some(exchange) = self.parent.exchange() {
+            builder = builder.exchange(*exchange);
+        }
+        let request = builder.build()?;
+        let response = self.korea.order(request).await?;
+        match response.output() {
+            Some(output) if response.rt_cd() == "0" => {
+                self.report.children.push(output.odno().parse()?);
+                self.report.sent_qty += qty;
+                Ok(())
+            }
+            _ => Err(Error::BrokenProtocol(
+                "Algo",
+                format!("{}: {}", response.msg_cd(), response.msg1()),
+            )),
+        }
+    }
+
+    async fn cancel_children(&self) {
+        for order in self
+            .report
+            .children
+            .iter()
+            .filter_map(|no| self.tracker.get(*no))
+            .filter(|order| !order.state().is_terminal())
+        {
+            if let Err(e) = self.korea.cancel(&order.order_ref()).await {
+                warn!("Failed to cancel child order {}: {}", order.order_no(), e);
+            }
+        }
+    }
+}
+
+async fn recv_market(market: &mut Option<mpsc::UnboundedReceiver<Exec>>) -> Option<Exec> {
+    match market {
+        Some(rx) => rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
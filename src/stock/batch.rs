@@ -0,0 +1,52 @@
+use crate::types::request::stock::order::{OrderRef, OrderRequest};
+use crate::types::response::stock::order::Body;
+use crate::Error;
+use getset::Getters;
+
+/// 일괄 주문 한 건의 결과
+#[derive(Debug)]
+pub enum BatchOutcome {
+    /// 접수(주문번호)
+    Accepted(OrderRef),
+    /// 거부(응답 메시지)
+    Rejected { msg_cd: String, msg1: String },
+    /// 전송 전 점검(위험 한도, 상/하한가 등), 토큰/hashkey 발급 또는 연결 실패로 전송하지 않음
+    NotSent(Error),
+    /// 전송 후 응답을 받지 못함. 주문이 접수되었을 수 있으므로 재전송 전에 주문체결조회로 확인
+    Unconfirmed(Error),
+}
+
+impl BatchOutcome {
+    pub(crate) fn from_result(request: &OrderRequest, result: Result<Body::Order, Error>) -> Self {
+        match result {
+            Ok(response) => match OrderRef::from_response(request.clone(), &response) {
+                Ok(order) => Self::Accepted(order),
+                Err(_) => Self::Rejected {
+                    msg_cd: response.msg_cd().clone(),
+                    msg1: response.msg1().clone(),
+                },
+            },
+            Err(e @ Error::OrderUnconfirmed(_)) => Self::Unconfirmed(e),
+            Err(e) => Self::NotSent(e),
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Self::Accepted(_))
+    }
+}
+
+/// 일괄 주문 결과(요청 순서 유지)
+#[derive(Debug, Getters)]
+pub struct BatchResult {
+    #[getset(get = "pub")]
+    request: OrderRequest,
+    #[getset(get = "pub")]
+    outcome: BatchOutcome,
+}
+
+impl BatchResult {
+    pub(crate) fn new(request: OrderRequest, outcome: BatchOutcome) -> Self {
+        Self { request, outcome }
+    }
+}
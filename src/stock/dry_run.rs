@@ -0,0 +1,125 @@
+use crate::types::response::stock::order::{Body, Output};
+use crate::Error;
+use getset::Getters;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+
+/// 드라이런 주문번호 시작값(실제 주문번호와 구분)
+const ORDER_NO_BASE: u64 = 9_000_000_000;
+
+/// 드라이런으로 전송하지 않은 요청
+#[derive(Clone, Debug, Getters)]
+pub struct DryRunRequest {
+    /// 생성한 주문번호
+    #[getset(get = "pub")]
+    odno: String,
+    #[getset(get = "pub")]
+    method: String,
+    #[getset(get = "pub")]
+    url: String,
+    /// 요청 헤더(authorization, appkey, appsecret, hashkey 포함)
+    #[getset(get = "pub")]
+    headers: Vec<(String, String)>,
+    #[getset(get = "pub")]
+    body: String,
+}
+
+/// 주문을 보내지 않고 요청을 기록한 뒤 가상의 응답을 반환
+#[derive(Clone)]
+pub(crate) struct DryRun {
+    seq: Arc<AtomicU64>,
+    tx: UnboundedSender<DryRunRequest>,
+}
+
+impl DryRun {
+    pub(crate) fn new() -> (Self, UnboundedReceiver<DryRunRequest>) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        (
+            Self {
+                seq: Arc::new(AtomicU64::new(1)),
+                tx,
+            },
+            rx,
+        )
+    }
+
+    pub(crate) fn respond(&self, request: reqwest::Request) -> Result<Body::Order, Error> {
+        let odno = self.record(request)?;
+        let ord_tmd = chrono::Utc::now()
+            .with_timezone(&chrono_tz::Asia::Seoul)
+            .format("%H%M%S")
+            .to_string();
+        Ok(Body::Order::new(
+            "0".to_string(),
+            "DRYRUN".to_string(),
+            "dry run: not sent".to_string(),
+            Some(Output::Order::new("00000".to_string(), odno, ord_tmd)),
+        ))
+    }
+
+    /// 예약주문 응답(가상의 주문번호를 예약주문순번으로 사용)
+    pub(crate) fn respond_reservation(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<Body::ReserveOrder, Error> {
+        let rsvn_ord_seq = self.record(request)?;
+        Ok(Body::ReserveOrder::new(
+            "0".to_string(),
+            "DRYRUN".to_string(),
+            "dry run: not sent".to_string(),
+            Some(Output::ReserveOrder::new(rsvn_ord_seq)),
+        ))
+    }
+
+    /// 예약주문정정취소 응답
+    pub(crate) fn respond_reservation_correction(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<Body::ReserveCorrection, Error> {
+        self.record(request)?;
+        Ok(Body::ReserveCorrection::new(
+            "0".to_string(),
+            "DRYRUN".to_string(),
+            "dry run: not sent".to_string(),
+            Some(Output::ReserveCorrection::new("Y".to_string())),
+        ))
+    }
+
+    /// 요청을 내보내고 가상의 주문번호 반환
+    fn record(&self, request: reqwest::Request) -> Result<String, Error> {
+        let odno = format!(
+            "{:010}",
+            ORDER_NO_BASE + self.seq.fetch_add(1, Ordering::Relaxed)
+        );
+        let body = match request.body().and_then(|body| body.as_bytes()) {
+            Some(bytes) => std::str::from_utf8(bytes)?.to_string(),
+            None => String::new(),
+        };
+        let tr_id = request
+            .headers()
+            .get("tr_id")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        info!(
+            "[DRY RUN] odno={} tr_id={} {} {} {}",
+            odno,
+            tr_id,
+            request.method(),
+            request.url(),
+            body
+        );
+        let _ = self.tx.send(DryRunRequest {
+            odno: odno.clone(),
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            headers: request
+                .headers()
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or_default().to_string()))
+                .collect(),
+            body,
+        });
+        Ok(odno)
+    }
+}
@@ -0,0 +1,263 @@
+use crate::types::response::stock::order::Body;
+use crate::types::stream::stock::my_exec;
+use crate::types::{
+    AcceptClass, CorrectionClass, Direction, Exchange, OrderClass, Price, Quantity, Time,
+};
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+const TIME_FORMAT: &str = "%Y%m%d%H%M%S";
+
+/// 주문 기록 한 줄
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum JournalEntry {
+    /// 주문 의도(로컬 점검 통과 후, 전송 전)
+    Order {
+        id: u64,
+        time: String,
+        pdno: String,
+        direction: Direction,
+        order_class: OrderClass,
+        qty: Quantity,
+        price: Price,
+        #[serde(default)]
+        exchange: Option<Exchange>,
+    },
+    /// 정정/취소 의도
+    Correction {
+        id: u64,
+        time: String,
+        krx_fwdg_ord_orgno: String,
+        orgn_odno: String,
+        correction_class: CorrectionClass,
+        order_class: OrderClass,
+        qty_all_ord_yn: bool,
+        qty: Quantity,
+        price: Price,
+    },
+    /// 예약주문/예약주문정정취소 의도(신규 예약주문은 correction_class가 None)
+    Reservation {
+        id: u64,
+        time: String,
+        pdno: String,
+        direction: Direction,
+        order_class: OrderClass,
+        qty: Quantity,
+        price: Price,
+        correction_class: CorrectionClass,
+        rsvn_ord_seq: String,
+    },
+    /// 전송한 요청
+    Submitted {
+        id: u64,
+        time: String,
+        tr_id: String,
+        body: String,
+    },
+    /// 응답
+    Response {
+        id: u64,
+        time: String,
+        response: Body::Order,
+    },
+    /// 예약주문/예약주문정정취소 응답
+    ReservationResponse {
+        id: u64,
+        time: String,
+        response: serde_json::Value,
+    },
+    /// 응답을 받지 못함(전송 여부 불명)
+    Failed {
+        id: u64,
+        time: String,
+        error: String,
+    },
+    /// 체결통보
+    Exec { time: String, exec: ExecRecord },
+}
+
+impl JournalEntry {
+    /// 주문/정정 요청 식별자(체결통보는 None)
+    pub fn id(&self) -> Option<u64> {
+        match self {
+            Self::Order { id, .. }
+            | Self::Correction { id, .. }
+            | Self::Reservation { id, .. }
+            | Self::Submitted { id, .. }
+            | Self::Response { id, .. }
+            | Self::ReservationResponse { id, .. }
+            | Self::Failed { id, .. } => Some(*id),
+            Self::Exec { .. } => None,
+        }
+    }
+
+    /// 기록 시각(YYYYMMDDHHMMSS)
+    pub fn time(&self) -> &str {
+        match self {
+            Self::Order { time, .. }
+            | Self::Correction { time, .. }
+            | Self::Reservation { time, .. }
+            | Self::Submitted { time, .. }
+            | Self::Response { time, .. }
+            | Self::ReservationResponse { time, .. }
+            | Self::Failed { time, .. }
+            | Self::Exec { time, .. } => time,
+        }
+    }
+}
+
+/// 체결통보 기록
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExecRecord {
+    pub order_no: u64,
+    pub origin_order_no: u64,
+    pub ask_bid_class: Direction,
+    pub correction_class: CorrectionClass,
+    pub order_kind: OrderClass,
+    pub shortcode: String,
+    pub exec_qty: u64,
+    pub exec_price: u32,
+    pub exec_time: String,
+    pub is_refused: bool,
+    pub is_executed: bool,
+    #[serde(default)]
+    pub accept_class: AcceptClass,
+    pub order_qty: u64,
+}
+
+impl From<&my_exec::Body> for ExecRecord {
+    fn from(body: &my_exec::Body) -> Self {
+        Self {
+            order_no: body.order_no,
+            origin_order_no: body.origin_order_no,
+            ask_bid_class: body.ask_bid_class.clone(),
+            correction_class: body.correction_class.clone(),
+            order_kind: body.order_kind.clone(),
+            shortcode: body.shortcode.clone(),
+            exec_qty: body.exec_qty,
+            exec_price: body.exec_price,
+            exec_time: body.exec_time.inner().format(TIME_FORMAT).to_string(),
+            is_refused: body.is_refused,
+            is_executed: body.is_executed,
+            accept_class: body.accept_class,
+            order_qty: body.order_qty,
+        }
+    }
+}
+
+impl ExecRecord {
+    #[allow(deprecated)]
+    pub(crate) fn into_body(self) -> Result<my_exec::Body, Error> {
+        Ok(my_exec::Body {
+            cust_id: String::new(),
+            acnt_no: String::new(),
+            order_no: self.order_no,
+            origin_order_no: self.origin_order_no,
+            ask_bid_class: self.ask_bid_class,
+            correction_class: self.correction_class,
+            order_kind: self.order_kind,
+            order_condition: String::new(),
+            shortcode: self.shortcode,
+            exec_qty: self.exec_qty,
+            exec_price: self.exec_price,
+            exec_time: Time::parse(&self.exec_time, TIME_FORMAT)?,
+            is_refused: self.is_refused,
+            is_executed: self.is_executed,
+            is_accepted: self.accept_class == AcceptClass::Received,
+            accept_class: self.accept_class,
+            branch_no: String::new(),
+            order_qty: self.order_qty,
+            account_name: String::new(),
+            stock_name: String::new(),
+            credit_class: String::new(),
+            credit_loan_date: None,
+            stock_name_40: String::new(),
+        })
+    }
+}
+
+/// 주문 의도, 요청, 응답, 체결통보를 JSON lines로 남기는 추가 전용 기록
+#[derive(Clone)]
+pub struct Journal {
+    path: PathBuf,
+    file: Arc<Mutex<std::fs::File>>,
+    seq: Arc<AtomicU64>,
+}
+
+impl Journal {
+    /// 기록 파일을 열고(없으면 생성) 기존 기록을 함께 반환
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<(Self, Vec<JournalEntry>), Error> {
+        let path = path.as_ref().to_path_buf();
+        let entries = if path.exists() {
+            Self::read(&path)?
+        } else {
+            vec![]
+        };
+        let next = entries.iter().filter_map(|e| e.id()).max().unwrap_or(0) + 1;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        // 잘린 마지막 줄 뒤에 이어 쓰지 않도록 줄바꿈 보충
+        let bytes = std::fs::read(&path)?;
+        if bytes.last().is_some_and(|b| *b != b'\n') {
+            file.write_all(b"\n")?;
+        }
+        Ok((
+            Self {
+                path,
+                file: Arc::new(Mutex::new(file)),
+                seq: Arc::new(AtomicU64::new(next)),
+            },
+            entries,
+        ))
+    }
+
+    /// 기록 파일 읽기. 기록 도중 중단되어 잘린 줄은 건너뜀
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Vec<JournalEntry>, Error> {
+        let file = std::fs::File::open(path)?;
+        let mut entries = vec![];
+        for line in std::io::BufReader::new(file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(&line) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => warn!("Skip broken journal line: {}", e),
+            }
+        }
+        Ok(entries)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub(crate) fn next_id(&self) -> u64 {
+        self.seq.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// 한 줄 기록 후 디스크에 반영
+    pub fn record(&self, entry: &JournalEntry) -> Result<(), Error> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+        let mut file = self.file.lock().unwrap();
+        file.write_all(line.as_bytes())?;
+        file.sync_data()?;
+        Ok(())
+    }
+}
+
+/// 기록 시각(한국시간)
+pub(crate) fn now() -> String {
+    chrono::Utc::now()
+        .with_timezone(&chrono_tz::Asia::Seoul)
+        .format(TIME_FORMAT)
+        .to_string()
+}
@@ -0,0 +1,75 @@
+use crate::stock::quote::Quote;
+use crate::types::{Exchange, MarketCode, Price};
+use crate::Error;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// (종목코드, 시장) -> (영업일자, 하한가, 상한가)
+type LimitCache = HashMap<(String, String), (String, u32, u32)>;
+
+/// 가격제한폭(상/하한가) 사전 점검
+/// 종목별 상/하한가는 주식현재가 시세로 조회해 하루 단위로 캐시
+#[derive(Clone)]
+pub struct PriceLimit {
+    quote: Quote,
+    cache: Arc<Mutex<LimitCache>>,
+}
+
+impl PriceLimit {
+    pub fn new(quote: &Quote) -> Self {
+        Self {
+            quote: quote.clone(),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 주문을 보낼 거래소 시세 기준 당일 (하한가, 상한가)
+    pub async fn get(&self, pdno: &str, exchange: &Exchange) -> Result<(Price, Price), Error> {
+        let today = today();
+        let market = MarketCode::from(exchange);
+        let key = (pdno.to_string(), market.to_string());
+        if let Some((date, lower, upper)) = self.cache.lock().unwrap().get(&key) {
+            if *date == today {
+                return Ok((Price::from(*lower), Price::from(*upper)));
+            }
+        }
+        let response = self.quote.current_price(market, pdno).await?;
+        let output = match response.output() {
+            Some(output) if response.rt_cd() == "0" => output,
+            _ => {
+                return Err(Error::BrokenProtocol(
+                    "PriceLimit",
+                    format!("{}: {}", response.msg_cd(), response.msg1()),
+                ));
+            }
+        };
+        let lower = output.stck_llam().parse::<u32>()?;
+        let upper = output.stck_mxpr().parse::<u32>()?;
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(key, (today, lower, upper));
+        Ok((Price::from(lower), Price::from(upper)))
+    }
+
+    /// 주문가격이 당일 상/하한가 범위 안인지 확인
+    pub async fn check(&self, pdno: &str, exchange: &Exchange, price: Price) -> Result<(), Error> {
+        let (lower, upper) = self.get(pdno, exchange).await?;
+        if price.inner() < lower.inner() || price.inner() > upper.inner() {
+            return Err(Error::PriceLimitExceeded(
+                pdno.to_string(),
+                price.inner(),
+                lower.inner(),
+                upper.inner(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn today() -> String {
+    chrono::Utc::now()
+        .with_timezone(&chrono_tz::Asia::Seoul)
+        .format("%Y%m%d")
+        .to_string()
+}
@@ -1,3 +1,15 @@
+pub mod algo;
+pub mod batch;
 pub mod data;
+pub mod dry_run;
+pub mod journal;
+pub mod limit;
 pub mod order;
 pub mod quote;
+pub mod rate_limit;
+pub mod reconcile;
+pub mod risk;
+#[cfg(test)]
+mod testing;
+pub mod tracker;
+pub mod trigger;
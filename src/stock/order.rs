@@ -1,9 +1,24 @@
+use crate::stock::algo::{Algo, AlgoHandle, AlgoStrategy};
+use crate::stock::batch::{BatchOutcome, BatchResult};
+use crate::stock::dry_run::{DryRun, DryRunRequest};
+use crate::stock::journal::{self, Journal, JournalEntry};
+use crate::stock::rate_limit::RateLimiter;
+use crate::stock::tracker::{self, TrackedOrder};
+use crate::stock::{limit::PriceLimit, quote::Quote, risk::RiskEngine, tracker::OrderTracker};
+use crate::types::response::stock::order::Body::Continued;
+use crate::types::session::Session;
 use crate::types::{
-    request, response, Account, CorrectionClass, Direction, Environment, OrderClass, Price,
-    Quantity, TrId,
+    request, response, Account, BalanceInquiryClass, CorrectionClass, Direction, Environment,
+    Exchange, ExecutionClass, OrderClass, Price, Quantity, ReservationProcessClass, SortClass,
+    Time, TrId,
 };
 use crate::{auth, Error};
 
+/// 초당 주문 전송 한도 기본값(실전투자)
+const REAL_ORDERS_PER_SEC: u32 = 20;
+/// 초당 주문 전송 한도 기본값(모의투자)
+const VIRTUAL_ORDERS_PER_SEC: u32 = 2;
+
 #[derive(Clone)]
 pub struct Korea {
     client: reqwest::Client,
@@ -11,6 +26,14 @@ pub struct Korea {
     environment: Environment,
     auth: auth::Auth,
     account: Account,
+    price_limit: Option<PriceLimit>,
+    tracker: Option<OrderTracker>,
+    risk: Option<RiskEngine>,
+    dry_run: Option<DryRun>,
+    journal: Option<Journal>,
+    rate_limiter: RateLimiter,
+    session_check: bool,
+    exchange: Option<Exchange>,
 }
 
 impl Korea {
@@ -27,15 +50,364 @@ impl Korea {
             Environment::Virtual => "https://openapivts.koreainvestment.com:29443",
         }
         .to_string();
+        let rate_limiter = RateLimiter::new(match environment {
+            Environment::Real => REAL_ORDERS_PER_SEC,
+            Environment::Virtual => VIRTUAL_ORDERS_PER_SEC,
+        });
         Ok(Self {
             client: client.clone(),
             endpoint_url,
             environment,
             auth,
             account,
+            price_limit: None,
+            tracker: None,
+            risk: None,
+            dry_run: None,
+            journal: None,
+            rate_limiter,
+            session_check: false,
+            exchange: None,
         })
     }
 
+    /// 주문 전 상/하한가 점검 사용
+    /// 지정가 계열 주문(가격 정정 포함)의 가격이 당일 가격제한폭을 벗어나면 API를 호출하지 않고
+    /// Error::PriceLimitExceeded를 반환
+    pub fn enable_price_limit_check(&mut self, quote: &Quote) {
+        self.price_limit = Some(PriceLimit::new(quote));
+    }
+
+    /// 주문 전 상/하한가 점검 해제
+    pub fn disable_price_limit_check(&mut self) {
+        self.price_limit = None;
+    }
+
+    /// 주문 전 매매 시간대 점검 사용
+    /// 주문구분이 주문 거래소의 현재 시간대에 접수되지 않으면(예: KRX 16:30의 지정가 주문) API를 호출하지 않고
+    /// Error::OrderClassNotInSession을 반환
+    pub fn enable_session_check(&mut self) {
+        self.session_check = true;
+    }
+
+    /// 주문/정정 기본 거래소(KRX, NXT, SOR). None이면 거래소 구분 없이 전송(KRX)
+    /// OrderRequest에 거래소를 지정하면 그 값이 우선
+    pub fn set_exchange(&mut self, exchange: Option<Exchange>) {
+        self.exchange = exchange;
+    }
+
+    /// NXT/SOR은 실전투자만 지원
+    fn check_exchange(&self, exchange: Option<Exchange>) -> Result<(), Error> {
+        match (&self.environment, exchange) {
+            (Environment::Virtual, Some(Exchange::Nxt | Exchange::Sor)) => Err(
+                Error::UnsupportedEnvironment(self.environment.clone(), "NXT/SOR routing"),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// 주문 전 매매 시간대 점검 해제
+    pub fn disable_session_check(&mut self) {
+        self.session_check = false;
+    }
+
+    /// order, order_cash, correct로 제출한 주문을 tracker에 자동 등록
+    pub fn set_tracker(&mut self, tracker: Option<&OrderTracker>) {
+        self.tracker = tracker.cloned();
+    }
+
+    /// 드라이런 사용: order_cash, correct, 예약주문은 검증과 hashkey 발급까지만 수행하고
+    /// 보낼 요청을 반환된 채널로 내보낸 뒤 가상의 주문번호로 응답
+    pub fn enable_dry_run(&mut self) -> tokio::sync::mpsc::UnboundedReceiver<DryRunRequest> {
+        let (dry_run, rx) = DryRun::new();
+        self.dry_run = Some(dry_run);
+        rx
+    }
+
+    pub fn disable_dry_run(&mut self) {
+        self.dry_run = None;
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run.is_some()
+    }
+
+    /// order_cash, correct의 주문 의도, 요청, 응답을 journal에 기록(드라이런 중에는 기록하지 않음)
+    pub fn set_journal(&mut self, journal: Option<&Journal>) {
+        self.journal = journal.cloned();
+    }
+
+    fn journal(&self) -> Option<&Journal> {
+        match self.dry_run {
+            Some(_) => None,
+            None => self.journal.as_ref(),
+        }
+    }
+
+    /// 주문 의도 기록. 기록하지 못하면 주문하지 않음
+    fn journal_intent(
+        &self,
+        entry: impl FnOnce(u64, String) -> JournalEntry,
+    ) -> Result<Option<u64>, Error> {
+        match self.journal() {
+            Some(journal) => {
+                let id = journal.next_id();
+                journal.record(&entry(id, journal::now()))?;
+                Ok(Some(id))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// 전송할 요청을 기록한 뒤 전송하고 응답(response_entry로 기록 항목 생성) 또는 실패를 기록
+    async fn send_journaled<T: serde::de::DeserializeOwned>(
+        &self,
+        id: Option<u64>,
+        tr_id: String,
+        body: String,
+        request: reqwest::RequestBuilder,
+        response_entry: impl FnOnce(u64, String, &T) -> JournalEntry,
+    ) -> Result<T, Error> {
+        let (journal, id) = match (self.journal(), id) {
+            (Some(journal), Some(id)) => (journal, id),
+            _ => return send_order(request).await,
+        };
+        journal.record(&JournalEntry::Submitted {
+            id,
+            time: journal::now(),
+            tr_id,
+            body,
+        })?;
+        let result = send_order(request).await;
+        let entry = match &result {
+            Ok(response) => response_entry(id, journal::now(), response),
+            Err(e) => JournalEntry::Failed {
+                id,
+                time: journal::now(),
+                error: e.to_string(),
+            },
+        };
+        if let Err(e) = journal.record(&entry) {
+            warn!("Failed to journal response: {}", e);
+        }
+        result
+    }
+
+    /// 재시작 후 journal 기록과 당일 주문체결조회로 tracker의 미체결 주문 상태를 복구
+    /// (전 거래일 기록은 무시)
+    ///
+    /// 1. 성공 응답을 받은 주문/정정과 체결통보를 순서대로 재생
+    /// 2. 응답을 받지 못한 주문은 종목, 매도/매수, 수량, 단가가 같은 당일 주문과 대응
+    /// 3. 당일 주문체결조회 결과로 체결수량과 상태를 보정
+    ///
+    /// 복구 후 미체결 주문을 반환
+    pub async fn recover(&self, entries: &[JournalEntry]) -> Result<Vec<TrackedOrder>, Error> {
+        // 전 거래일 기록은 당일 주문체결조회로 보정할 수 없으므로 제외
+        let trading_date = tracker::trading_date();
+        let tracker = match &self.tracker {
+            Some(tracker) => tracker,
+            None => {
+                return Err(Error::InvalidParameter(
+                    "tracker",
+                    "tracker is not set".to_string(),
+                ));
+            }
+        };
+        let mut intents = std::collections::HashMap::new();
+        let mut unresolved = vec![];
+        for entry in entries
+            .iter()
+            .filter(|e| e.time().starts_with(&trading_date))
+        {
+            match entry {
+                JournalEntry::Order { id, .. } | JournalEntry::Correction { id, .. } => {
+                    intents.insert(*id, entry);
+                }
+                JournalEntry::Response { id, response, .. } => {
+                    let (intent, output) = match (intents.remove(id), response.output()) {
+                        (Some(intent), Some(output)) if response.rt_cd() == "0" => (intent, output),
+                        _ => continue,
+                    };
+                    match intent {
+                        JournalEntry::Order {
+                            pdno,
+                            direction,
+                            order_class,
+                            qty,
+                            price,
+                            exchange,
+                            ..
+                        } => tracker.restore(
+                            output.odno().parse()?,
+                            output.krx_fwdg_ord_orgno(),
+                            &request::stock::order::OrderRequest::new(
+                                direction.clone(),
+                                pdno,
+                                order_class.clone(),
+                                qty.clone(),
+                                *price,
+                                *exchange,
+                            ),
+                        ),
+                        JournalEntry::Correction {
+                            orgn_odno,
+                            correction_class,
+                            price,
+                            ..
+                        } => {
+                            let result = orgn_odno.parse::<u64>().map_err(Error::from).and_then(
+                                |orgn_odno| {
+                                    tracker.apply_correction(
+                                        orgn_odno,
+                                        correction_class.clone(),
+                                        *price,
+                                        response,
+                                    )
+                                },
+                            );
+                            if let Err(e) = result {
+                                warn!("Failed to replay correction: {}", e);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                JournalEntry::Failed { id, .. } => {
+                    if let Some(intent @ JournalEntry::Order { .. }) = intents.remove(id) {
+                        unresolved.push(intent);
+                    }
+                }
+                JournalEntry::Exec { exec, .. } => tracker.apply_body(exec.clone().into_body()?),
+                JournalEntry::Submitted { .. }
+                | JournalEntry::Reservation { .. }
+                | JournalEntry::ReservationResponse { .. } => {}
+            }
+        }
+        // 응답 기록 전에 중단된 주문
+        unresolved.extend(
+            intents
+                .into_values()
+                .filter(|intent| matches!(intent, JournalEntry::Order { .. })),
+        );
+        unresolved.sort_by_key(|intent| intent.id());
+
+        let today = Time::parse(&trading_date, "%Y%m%d")?;
+        let mut rows = self
+            .inquire_daily_ccld_all(&today, &today, None, "", ExecutionClass::All)
+            .await?;
+        rows.sort_by(|a, b| a.odno().cmp(b.odno()));
+        let mut claimed = std::collections::HashSet::new();
+        for intent in unresolved {
+            if let JournalEntry::Order {
+                pdno,
+                direction,
+                order_class,
+                qty,
+                price,
+                exchange,
+                ..
+            } = intent
+            {
+                let row = rows.iter().find(|row| {
+                    !claimed.contains(row.odno())
+                        && row.orgn_odno().trim().is_empty()
+                        && row.pdno() == pdno
+                        && row.sll_buy_dvsn_cd() == &direction.to_string()
+                        && row.ord_qty().parse::<u32>().ok() == Some(qty.inner())
+                        && row.ord_unpr().parse::<u32>().ok() == Some(price.inner())
+                        && row
+                            .odno()
+                            .parse()
+                            .ok()
+                            .and_then(|odno| tracker.get(odno))
+                            .is_none()
+                });
+                if let Some(row) = row {
+                    claimed.insert(row.odno().clone());
+                    tracker.restore(
+                        row.odno().parse()?,
+                        row.ord_gno_brno(),
+                        &request::stock::order::OrderRequest::new(
+                            direction.clone(),
+                            pdno,
+                            order_class.clone(),
+                            qty.clone(),
+                            *price,
+                            *exchange,
+                        ),
+                    );
+                }
+            }
+        }
+        tracker.reconcile(&rows);
+        Ok(tracker
+            .orders()
+            .into_iter()
+            .filter(|order| !order.state().is_terminal())
+            .collect())
+    }
+
+    /// 초당 주문/정정 전송 수 한도 변경(기본값: 실전 20, 모의 2)
+    pub fn set_rate_limit(&mut self, per_sec: u32) -> Result<(), Error> {
+        if per_sec == 0 {
+            return Err(Error::InvalidParameter(
+                "per_sec",
+                "must be greater than 0".to_string(),
+            ));
+        }
+        self.rate_limiter = RateLimiter::new(per_sec);
+        Ok(())
+    }
+
+    /// order_cash, correct 전에 위험 한도 점검
+    pub fn set_risk(&mut self, risk: Option<&RiskEngine>) {
+        self.risk = risk.cloned();
+    }
+
+    /// 킬 스위치: 신규/정정 주문을 막고 미체결 주문을 모두 취소
+    /// 실전투자는 정정취소가능주문 전체, 모의투자는 tracker의 미체결 주문을 취소
+    pub async fn kill_switch(
+        &self,
+    ) -> Result<
+        Vec<(
+            request::stock::order::OrderRef,
+            Result<response::stock::order::Body::Order, Error>,
+        )>,
+        Error,
+    > {
+        match &self.risk {
+            Some(risk) => risk.engage_kill_switch(),
+            None => {
+                return Err(Error::InvalidParameter(
+                    "risk",
+                    "risk engine is not set".to_string(),
+                ));
+            }
+        }
+        if let Environment::Real = self.environment {
+            return self.cancel_all(|_| true).await;
+        }
+        let tracker = match &self.tracker {
+            Some(tracker) => tracker,
+            None => {
+                return Err(Error::InvalidParameter(
+                    "tracker",
+                    "tracker is not set".to_string(),
+                ));
+            }
+        };
+        let mut result = vec![];
+        for order in tracker.orders() {
+            if order.state().is_terminal() {
+                continue;
+            }
+            let order = order.order_ref();
+            let outcome = self.cancel(&order).await;
+            result.push((order, outcome));
+        }
+        Ok(result)
+    }
+
     /// 주식주문(현금)[v1_국내주식-001]
     /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_aade4c72-5fb7-418a-9ff2-254b4d5f0ceb)
     pub async fn order_cash(
@@ -46,15 +418,44 @@ impl Korea {
         qty: Quantity,
         price: Price,
     ) -> Result<response::stock::order::Body::Order, Error> {
-        let request = request::stock::order::Body::Order::new(
-            self.account.cano.clone(),
-            self.account.acnt_prdt_cd.clone(),
-            pdno.to_string(),
+        self.submit_cash(request::stock::order::OrderRequest::new(
+            order_direction,
+            pdno,
             order_division,
             qty,
             price,
-        )
-        .get_json_string();
+            self.exchange,
+        ))
+        .await
+    }
+
+    async fn submit_cash(
+        &self,
+        order: request::stock::order::OrderRequest,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        let order_division = order.order_class();
+        let order_direction = order.direction();
+        let pdno = order.pdno().as_str();
+        let qty = order.qty();
+        let price = *order.price();
+        let exchange = *order.exchange();
+        self.check_exchange(exchange)?;
+        if self.session_check {
+            Session::now_on(&exchange.unwrap_or_default()).validate(order_division)?;
+        }
+        // 전송(및 tracker 등록)이 끝날 때까지 위험 한도 예약
+        let _reservation = match &self.risk {
+            Some(risk) => Some(risk.reserve_order(order_direction, pdno, qty, &price)?),
+            None => None,
+        };
+        if let Some(price_limit) = &self.price_limit {
+            if !order_division.is_priceless() {
+                price_limit
+                    .check(pdno, &exchange.unwrap_or_default(), price)
+                    .await?;
+            }
+        }
+        let request = order.clone().into_body(&self.account).get_json_string();
         let tr_id: String = match self.environment {
             Environment::Real => match order_direction {
                 Direction::Bid => TrId::RealStockCashBidOrder.into(),
@@ -65,8 +466,9 @@ impl Korea {
                 Direction::Ask => TrId::VirtualStockCashAskOrder.into(),
             },
         };
+        self.rate_limiter.acquire().await;
         let hash = self.auth.get_hash(request.clone()).await?;
-        Ok(self
+        let builder = self
             .client
             .post(format!(
                 "{}/uapi/domestic-stock/v1/trading/order-cash",
@@ -84,14 +486,105 @@ impl Korea {
             )
             .header("appkey", self.auth.get_appkey())
             .header("appsecret", self.auth.get_appsecret())
-            .header("tr_id", tr_id)
+            .header("tr_id", tr_id.clone())
             .header("hashkey", hash)
             .header("custtype", "P")
-            .body(request)
-            .send()
-            .await?
-            .json::<response::stock::order::Body::Order>()
-            .await?)
+            .body(request.clone());
+        // 로컬 점검과 토큰/hashkey 발급이 끝난 뒤에 의도를 기록
+        let id = self.journal_intent(|id, time| JournalEntry::Order {
+            id,
+            time,
+            pdno: pdno.to_string(),
+            direction: order_direction.clone(),
+            order_class: order_division.clone(),
+            qty: qty.clone(),
+            price,
+            exchange,
+        })?;
+        let response = match &self.dry_run {
+            Some(dry_run) => dry_run.respond(builder.build()?)?,
+            None => {
+                self.send_journaled(id, tr_id, request, builder, order_response)
+                    .await?
+            }
+        };
+        if let Some(tracker) = &self.tracker {
+            if response.rt_cd() == "0" {
+                let result = tracker.register(&order, &response);
+                if let Err(e) = result {
+                    warn!("Failed to track order: {}", e);
+                }
+            }
+        }
+        Ok(response)
+    }
+
+    /// 주식주문(현금)[v1_국내주식-001]
+    /// OrderRequestBuilder로 검증된 주문을 제출
+    pub async fn order(
+        &self,
+        request: request::stock::order::OrderRequest,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        self.submit_cash(request.or_exchange(self.exchange)).await
+    }
+
+    /// 주식주문(현금)[v1_국내주식-001]
+    /// 주문을 제출하고 정정/취소/조회에 쓸 OrderRef를 반환(거부되면 Error::OrderRejected)
+    pub async fn submit(
+        &self,
+        request: request::stock::order::OrderRequest,
+    ) -> Result<request::stock::order::OrderRef, Error> {
+        let request = request.or_exchange(self.exchange);
+        let response = self.order(request.clone()).await?;
+        request::stock::order::OrderRef::from_response(request, &response)
+    }
+
+    /// 일괄 주문: 주문을 동시에 제출하되 초당 전송 한도를 지키고,
+    /// 일부가 실패해도 나머지를 계속 진행해 요청 순서대로 결과를 반환
+    pub async fn order_batch(
+        &self,
+        requests: Vec<request::stock::order::OrderRequest>,
+    ) -> Vec<BatchResult> {
+        let handles = requests
+            .into_iter()
+            .map(|request| {
+                let korea = self.clone();
+                tokio::spawn(async move {
+                    let outcome =
+                        BatchOutcome::from_result(&request, korea.order(request.clone()).await);
+                    BatchResult::new(request, outcome)
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut result = Vec::with_capacity(handles.len());
+        for handle in handles {
+            match handle.await {
+                Ok(item) => result.push(item),
+                Err(e) => std::panic::resume_unwind(e.into_panic()),
+            }
+        }
+        result
+    }
+
+    /// 모주문을 자식 주문으로 나눠 집행(TWAP/POV/iceberg)
+    /// 체결 현황은 set_tracker로 지정한 tracker에서 가져오므로 tracker에 체결통보를 연결해야 하며,
+    /// market에는 해당 종목의 실시간 체결가(subscribe_market, RealtimeExec) 수신 채널을 넘김(POV 필수)
+    pub fn execute_algo(
+        &self,
+        parent: request::stock::order::OrderRequest,
+        strategy: AlgoStrategy,
+        market: Option<tokio::sync::mpsc::UnboundedReceiver<crate::types::stream::stock::Exec>>,
+    ) -> Result<AlgoHandle, Error> {
+        let tracker = match &self.tracker {
+            Some(tracker) => tracker.clone(),
+            None => {
+                return Err(Error::InvalidParameter(
+                    "tracker",
+                    "tracker is not set".to_string(),
+                ));
+            }
+        };
+        Algo::start(self.clone(), tracker, parent, strategy, market)
     }
 
     // TODO: 주식주문(신용)[v1_국내주식-002]
@@ -109,7 +602,7 @@ impl Korea {
         qty: Quantity,
         price: Price,
     ) -> Result<response::stock::order::Body::Order, Error> {
-        let request = request::stock::order::Body::Correction::new(
+        let mut request = request::stock::order::Body::Correction::new(
             self.account.cano.clone(),
             self.account.acnt_prdt_cd.clone(),
             krx_fwdg_ord_orgno.to_string(),
@@ -119,14 +612,88 @@ impl Korea {
             qty,
             price,
             qty_all_ord_yn,
-        )
-        .get_json_string();
+        );
+        request.set_excg_id_dvsn_cd(self.exchange);
+        self.submit_correction(None, request).await
+    }
+
+    /// OrderRef의 주문에 대한 정정취소 요청
+    fn correction_body(
+        &self,
+        order: &request::stock::order::OrderRef,
+        order_division: OrderClass,
+        rvse_cncl_dvsn_cd: CorrectionClass,
+        qty_all_ord_yn: bool,
+        qty: Quantity,
+        price: Price,
+    ) -> request::stock::order::Body::Correction {
+        let mut request = request::stock::order::Body::Correction::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            order.krx_fwdg_ord_orgno().to_string(),
+            order.odno().to_string(),
+            order_division,
+            rvse_cncl_dvsn_cd,
+            qty,
+            price,
+            qty_all_ord_yn,
+        );
+        request.set_excg_id_dvsn_cd(self.order_exchange(order));
+        request
+    }
+
+    /// pdno는 상/하한가 점검에 쓰며, None이면 tracker에서 원주문의 종목코드를 찾음
+    async fn submit_correction(
+        &self,
+        pdno: Option<&str>,
+        request: request::stock::order::Body::Correction,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        let orgn_odno = request.orgn_odno().clone();
+        let order_division = request.ord_dvsn().clone();
+        let rvse_cncl_dvsn_cd = request.rvse_cncl_dvsn_cd().clone();
+        let qty_all_ord_yn = *request.qty_all_ord_yn();
+        let qty = request.ord_qty().clone();
+        let price = *request.ord_unpr();
+        let exchange = *request.excg_id_dvsn_cd();
+        self.check_exchange(exchange)?;
+        if let Some(risk) = &self.risk {
+            risk.check_correction(&orgn_odno, &rvse_cncl_dvsn_cd, qty_all_ord_yn, &qty, &price)?;
+        }
+        if let Some(price_limit) = &self.price_limit {
+            if let CorrectionClass::Correction = rvse_cncl_dvsn_cd {
+                if price.inner() != 0 && !order_division.is_priceless() {
+                    let pdno = match pdno {
+                        Some(pdno) => Some(pdno.to_string()),
+                        None => self.tracked_pdno(&orgn_odno),
+                    };
+                    match pdno {
+                        Some(pdno) => {
+                            price_limit
+                                .check(&pdno, &exchange.unwrap_or_default(), price)
+                                .await?
+                        }
+                        None => {
+                            return Err(Error::InvalidParameter(
+                                "pdno",
+                                format!(
+                                    "unknown product of order {} for price limit check",
+                                    orgn_odno
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        let krx_fwdg_ord_orgno = request.krx_fwdg_ord_orgno().clone();
+        let request = request.get_json_string();
         let tr_id: String = match self.environment {
             Environment::Real => TrId::RealStockCorrection.into(),
             Environment::Virtual => TrId::VirtualStockCorrection.into(),
         };
+        self.rate_limiter.acquire().await;
         let hash = self.auth.get_hash(request.clone()).await?;
-        Ok(self
+        let builder = self
             .client
             .post(format!(
                 "{}/uapi/domestic-stock/v1/trading/order-rvsecncl",
@@ -136,7 +703,7 @@ impl Korea {
             .header(
                 "Authorization",
                 match self.auth.get_token() {
-                    Some(token) => token,
+                    Some(token) => format!("Bearer {}", token),
                     None => {
                         return Err(Error::AuthInitFailed("token"));
                     }
@@ -144,24 +711,1160 @@ impl Korea {
             )
             .header("appkey", self.auth.get_appkey())
             .header("appsecret", self.auth.get_appsecret())
-            .header("tr_id", tr_id)
+            .header("tr_id", tr_id.clone())
             .header("hashkey", hash)
-            .body(request)
+            .body(request.clone());
+        // 로컬 점검과 토큰/hashkey 발급이 끝난 뒤에 의도를 기록
+        let id = self.journal_intent(|id, time| JournalEntry::Correction {
+            id,
+            time,
+            krx_fwdg_ord_orgno,
+            orgn_odno: orgn_odno.clone(),
+            correction_class: rvse_cncl_dvsn_cd.clone(),
+            order_class: order_division.clone(),
+            qty_all_ord_yn,
+            qty: qty.clone(),
+            price,
+        })?;
+        let response = match &self.dry_run {
+            Some(dry_run) => dry_run.respond(builder.build()?)?,
+            None => {
+                self.send_journaled(id, tr_id, request, builder, order_response)
+                    .await?
+            }
+        };
+        if let Some(tracker) = &self.tracker {
+            if response.rt_cd() == "0" {
+                let result = orgn_odno
+                    .parse::<u64>()
+                    .map_err(Error::from)
+                    .and_then(|orgn_odno| {
+                        tracker.apply_correction(orgn_odno, rvse_cncl_dvsn_cd, price, &response)
+                    });
+                if let Err(e) = result {
+                    warn!("Failed to track correction: {}", e);
+                }
+            }
+        }
+        Ok(response)
+    }
+
+    /// 주문 정정(잔량전부 또는 qty만큼)
+    /// 거래소는 OrderRef의 주문에 지정된 값, 없으면 set_exchange의 기본값
+    /// 정정 주문번호로 갱신한 OrderRef를 반환(거부되면 Error::OrderRejected)
+    pub async fn amend(
+        &self,
+        order: &request::stock::order::OrderRef,
+        order_division: OrderClass,
+        qty_all_ord_yn: bool,
+        qty: Quantity,
+        price: Price,
+    ) -> Result<request::stock::order::OrderRef, Error> {
+        let request = self.correction_body(
+            order,
+            order_division.clone(),
+            CorrectionClass::Correction,
+            qty_all_ord_yn,
+            qty,
+            price,
+        );
+        let response = self
+            .submit_correction(order.pdno().as_deref(), request)
+            .await?;
+        request::stock::order::OrderRef::from_correction(order, order_division, price, &response)
+    }
+
+    /// 주문 취소(잔량전부)
+    pub async fn cancel(
+        &self,
+        order: &request::stock::order::OrderRef,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        let request = self.correction_body(
+            order,
+            order_class(order),
+            CorrectionClass::Cancel,
+            true,
+            Quantity::from(0),
+            Price::from(0),
+        );
+        self.submit_correction(order.pdno().as_deref(), request)
+            .await
+    }
+
+    /// 주문 가격 정정(잔량전부, 지정가). 정정 주문번호로 갱신한 OrderRef를 반환
+    pub async fn modify_price(
+        &self,
+        order: &request::stock::order::OrderRef,
+        price: Price,
+    ) -> Result<request::stock::order::OrderRef, Error> {
+        self.amend(order, OrderClass::Limit, true, Quantity::from(0), price)
+            .await
+    }
+
+    /// 미체결 잔량을 qty로 감소(차이만큼 일부 취소)
+    /// 잔량은 set_tracker로 지정한 tracker, 없으면 정정취소가능주문조회로 확인
+    /// (tracker의 주문수량은 취소 체결통보를 받을 때 줄어듦)
+    pub async fn modify_qty(
+        &self,
+        order: &request::stock::order::OrderRef,
+        qty: Quantity,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        let remaining = self.remaining_qty(order).await?;
+        if qty.inner() >= remaining {
+            return Err(Error::InvalidParameter(
+                "ord_qty",
+                format!(
+                    "{} is not less than the remaining quantity {}",
+                    qty.inner(),
+                    remaining
+                ),
+            ));
+        }
+        if qty.inner() == 0 {
+            return self.cancel(order).await;
+        }
+        let request = self.correction_body(
+            order,
+            order_class(order),
+            CorrectionClass::Cancel,
+            false,
+            Quantity::from(remaining - qty.inner()),
+            Price::from(0),
+        );
+        self.submit_correction(order.pdno().as_deref(), request)
+            .await
+    }
+
+    fn tracked_pdno(&self, odno: &str) -> Option<String> {
+        let order_no = odno.trim().parse().ok()?;
+        self.tracker
+            .as_ref()?
+            .get(order_no)
+            .map(|order| order.pdno().clone())
+    }
+
+    fn order_exchange(&self, order: &request::stock::order::OrderRef) -> Option<Exchange> {
+        order.exchange().or(self.exchange)
+    }
+
+    /// 주식일별주문체결조회로 주문과 그 정정/취소 주문의 체결내역 조회
+    /// 주문일은 OrderRef의 주문시각, 없으면 당일 기준
+    pub async fn inquire_order(
+        &self,
+        order: &request::stock::order::OrderRef,
+    ) -> Result<Vec<response::stock::order::Output::InquireDailyCcld>, Error> {
+        let date = match order.ord_tmd() {
+            Some(time) => time.date(),
+            None => chrono::Utc::now()
+                .with_timezone(&chrono_tz::Asia::Seoul)
+                .format("%Y%m%d")
+                .to_string(),
+        };
+        let date = Time::parse(&date, "%Y%m%d")?;
+        let rows = self
+            .inquire_daily_ccld_all(
+                &date,
+                &date,
+                order.direction().clone(),
+                order.pdno().as_deref().unwrap_or(""),
+                ExecutionClass::All,
+            )
+            .await?;
+        Ok(rows
+            .into_iter()
+            .filter(|row| row.odno() == order.odno() || row.orgn_odno() == order.odno())
+            .collect())
+    }
+
+    /// 정정취소가능주문 중 filter에 맞는 주문을 모두 취소(실전투자 전용)
+    /// 주문별 결과를 반환하며, 일부가 실패해도 나머지는 계속 진행
+    pub async fn cancel_all<F>(
+        &self,
+        filter: F,
+    ) -> Result<
+        Vec<(
+            request::stock::order::OrderRef,
+            Result<response::stock::order::Body::Order, Error>,
+        )>,
+        Error,
+    >
+    where
+        F: Fn(&response::stock::order::Output::InquirePsblRvsecncl) -> bool,
+    {
+        let mut result = vec![];
+        for output in self.inquire_psbl_rvsecncl_all(None).await? {
+            if !filter(&output) {
+                continue;
+            }
+            let order = request::stock::order::OrderRef::from(&output);
+            let outcome = self.cancel(&order).await;
+            result.push((order, outcome));
+        }
+        Ok(result)
+    }
+
+    /// 주식정정취소가능주문조회[v1_국내주식-004]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_d4537e9c-73f7-414c-9fb0-4eae3bc397d0)
+    /// direction이 None이면 매도/매수 전체(실전투자 전용)
+    pub async fn inquire_psbl_rvsecncl(
+        &self,
+        direction: Option<Direction>,
+        ctx_area_fk100: &str,
+        ctx_area_nk100: &str,
+    ) -> Result<response::stock::order::Body::InquirePsblRvsecncl, Error> {
+        if let Environment::Virtual = self.environment {
+            return Err(Error::UnsupportedEnvironment(
+                self.environment.clone(),
+                "inquire_psbl_rvsecncl",
+            ));
+        }
+        let param = request::stock::order::InquirePsblRvsecnclParameter::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            direction,
+            ctx_area_fk100.to_string(),
+            ctx_area_nk100.to_string(),
+        );
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/trading/inquire-psbl-rvsecncl",
+            self.endpoint_url
+        );
+        let url = reqwest::Url::parse_with_params(&url, &param.into_iter())?;
+        let response = self
+            .create_request(
+                TrId::InquirePsblRvsecncl,
+                url,
+                !ctx_area_nk100.trim().is_empty(),
+            )?
             .send()
+            .await?;
+        let tr_cont = get_tr_cont(&response);
+        let mut body = response
+            .json::<response::stock::order::Body::InquirePsblRvsecncl>()
+            .await?;
+        body.set_tr_cont(tr_cont);
+        Ok(body)
+    }
+
+    /// 주식정정취소가능주문조회[v1_국내주식-004]
+    /// 연속조회를 끝까지 반복해 전체 정정취소가능주문을 반환
+    pub async fn inquire_psbl_rvsecncl_all(
+        &self,
+        direction: Option<Direction>,
+    ) -> Result<Vec<response::stock::order::Output::InquirePsblRvsecncl>, Error> {
+        let mut result = vec![];
+        self.fetch_all(
+            "inquire_psbl_rvsecncl",
+            |ctx_area_fk100, ctx_area_nk100| {
+                let direction = direction.clone();
+                async move {
+                    self.inquire_psbl_rvsecncl(direction, &ctx_area_fk100, &ctx_area_nk100)
+                        .await
+                }
+            },
+            |page| {
+                if let Some(output) = page.output() {
+                    result.extend(output.iter().cloned());
+                }
+            },
+        )
+        .await?;
+        Ok(result)
+    }
+
+    async fn remaining_qty(&self, order: &request::stock::order::OrderRef) -> Result<u32, Error> {
+        if let Some(tracker) = &self.tracker {
+            if let Some(tracked) = order.odno().parse().ok().and_then(|no| tracker.get(no)) {
+                return Ok(tracked.remaining_qty() as u32);
+            }
+        }
+        match self
+            .inquire_psbl_rvsecncl_all(None)
             .await?
-            .json::<response::stock::order::Body::Order>()
+            .iter()
+            .find(|output| output.odno() == order.odno())
+        {
+            Some(output) => Ok(output.psbl_qty().parse()?),
+            None => Err(Error::InvalidParameter(
+                "odno",
+                format!("{} is not a cancellable order", order.odno()),
+            )),
+        }
+    }
+
+    /// 주식일별주문체결조회[v1_국내주식-005]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_bc51f9f7-146f-4971-a5ae-ebd574acec12)
+    /// 3개월 이내 주문의 체결내역(direction이 None이면 매도/매수 전체, pdno가 공란이면 전체 종목)
+    /// 다음 페이지는 이전 응답의 ctx_area_fk100, ctx_area_nk100을 params에 넣어 다시 호출
+    pub async fn inquire_daily_ccld(
+        &self,
+        params: request::stock::order::InquireDailyCcldParameter,
+    ) -> Result<response::stock::order::Body::InquireDailyCcld, Error> {
+        let tr_id = match self.environment {
+            Environment::Real => TrId::RealInquireDailyCcld,
+            Environment::Virtual => TrId::VirtualInquireDailyCcld,
+        };
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/trading/inquire-daily-ccld",
+            self.endpoint_url
+        );
+        let url = reqwest::Url::parse_with_params(&url, &params.into_iter())?;
+        let response = self
+            .create_request(tr_id, url, !params.ctx_area_nk100().trim().is_empty())?
+            .send()
+            .await?;
+        let tr_cont = get_tr_cont(&response);
+        let mut body = response
+            .json::<response::stock::order::Body::InquireDailyCcld>()
+            .await?;
+        body.set_tr_cont(tr_cont);
+        Ok(body)
+    }
+
+    /// 주식일별주문체결조회[v1_국내주식-005]
+    /// 연속조회를 끝까지 반복해 기간 내 전체 주문별 체결내역을 반환
+    pub async fn inquire_daily_ccld_all(
+        &self,
+        start: &Time,
+        end: &Time,
+        direction: Option<Direction>,
+        pdno: &str,
+        ccld_dvsn: ExecutionClass,
+    ) -> Result<Vec<response::stock::order::Output::InquireDailyCcld>, Error> {
+        let params = request::stock::order::InquireDailyCcldParameter::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            start.date(),
+            end.date(),
+            direction,
+            pdno.to_string(),
+            ccld_dvsn,
+        );
+        let mut result = vec![];
+        self.fetch_all(
+            "inquire_daily_ccld",
+            |ctx_area_fk100, ctx_area_nk100| {
+                let mut params = params.clone();
+                params
+                    .set_ctx_area_fk100(ctx_area_fk100)
+                    .set_ctx_area_nk100(ctx_area_nk100);
+                async move { self.inquire_daily_ccld(params).await }
+            },
+            |page| {
+                if let Some(output) = page.output1() {
+                    result.extend(output.iter().cloned());
+                }
+            },
+        )
+        .await?;
+        Ok(result)
+    }
+
+    /// 주식잔고조회[v1_국내주식-006]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_66c61080-674f-4c91-a0cc-db5e64e9a5e6)
+    /// 한 번에 최대 50건(모의투자 20건)까지 조회되며, 다음 페이지는
+    /// 이전 응답의 ctx_area_fk100, ctx_area_nk100을 넣어 다시 호출
+    pub async fn inquire_balance(
+        &self,
+        inqr_dvsn: BalanceInquiryClass,
+        afhr_flpr_yn: bool,
+        fund_sttl_icld_yn: bool,
+        prcs_dvsn_include_yesterday: bool,
+        ctx_area_fk100: &str,
+        ctx_area_nk100: &str,
+    ) -> Result<response::stock::order::Body::InquireBalance, Error> {
+        let mut param = request::stock::order::InquireBalanceParameter::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            inqr_dvsn,
+            afhr_flpr_yn,
+            fund_sttl_icld_yn,
+            prcs_dvsn_include_yesterday,
+        );
+        param
+            .set_ctx_area_fk100(ctx_area_fk100.to_string())
+            .set_ctx_area_nk100(ctx_area_nk100.to_string());
+        let tr_id = match self.environment {
+            Environment::Real => TrId::RealInquireBalance,
+            Environment::Virtual => TrId::VirtualInquireBalance,
+        };
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/trading/inquire-balance",
+            self.endpoint_url
+        );
+        let url = reqwest::Url::parse_with_params(&url, &param.into_iter())?;
+        let response = self
+            .create_request(tr_id, url, !ctx_area_nk100.trim().is_empty())?
+            .send()
+            .await?;
+        let tr_cont = get_tr_cont(&response);
+        let mut body = response
+            .json::<response::stock::order::Body::InquireBalance>()
+            .await?;
+        body.set_tr_cont(tr_cont);
+        Ok(body)
+    }
+
+    /// 주식잔고조회[v1_국내주식-006]
+    /// 연속조회를 끝까지 반복해 전체 보유종목과 마지막 페이지의 계좌 요약을 반환
+    pub async fn inquire_balance_all(
+        &self,
+        inqr_dvsn: BalanceInquiryClass,
+        afhr_flpr_yn: bool,
+        fund_sttl_icld_yn: bool,
+        prcs_dvsn_include_yesterday: bool,
+    ) -> Result<
+        (
+            Vec<response::stock::order::Output::InquireBalance>,
+            Option<response::stock::order::Output::InquireBalanceSummary>,
+        ),
+        Error,
+    > {
+        let mut holdings = vec![];
+        let mut summary = None;
+        self.fetch_all(
+            "inquire_balance",
+            |ctx_area_fk100, ctx_area_nk100| {
+                let inqr_dvsn = inqr_dvsn.clone();
+                async move {
+                    self.inquire_balance(
+                        inqr_dvsn,
+                        afhr_flpr_yn,
+                        fund_sttl_icld_yn,
+                        prcs_dvsn_include_yesterday,
+                        &ctx_area_fk100,
+                        &ctx_area_nk100,
+                    )
+                    .await
+                }
+            },
+            |page| {
+                if let Some(output1) = page.output1() {
+                    holdings.extend(output1.iter().cloned());
+                }
+                if let Some(s) = page.output2().as_ref().and_then(|output2| output2.first()) {
+                    summary = Some(s.clone());
+                }
+            },
+        )
+        .await?;
+        Ok((holdings, summary))
+    }
+
+    /// 매수가능조회[v1_국내주식-007]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_806e407c-3082-44c0-9d71-e8534db5ad54)
+    /// 시장가(OrderClass::Market) 조회시 ord_unpr는 None
+    pub async fn inquire_psbl_order(
+        &self,
+        pdno: &str,
+        ord_unpr: Option<Price>,
+        ord_dvsn: OrderClass,
+        cma_evlu_amt_icld_yn: bool,
+        ovrs_icld_yn: bool,
+    ) -> Result<response::stock::order::Body::InquirePsblOrder, Error> {
+        let param = request::stock::order::InquirePsblOrderParameter::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            pdno.to_string(),
+            ord_unpr,
+            ord_dvsn,
+            cma_evlu_amt_icld_yn,
+            ovrs_icld_yn,
+        );
+        let tr_id = match self.environment {
+            Environment::Real => TrId::RealInquirePsblOrder,
+            Environment::Virtual => TrId::VirtualInquirePsblOrder,
+        };
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/trading/inquire-psbl-order",
+            self.endpoint_url
+        );
+        let url = reqwest::Url::parse_with_params(&url, &param.into_iter())?;
+        Ok(self
+            .create_request(tr_id, url, false)?
+            .send()
+            .await?
+            .json::<response::stock::order::Body::InquirePsblOrder>()
             .await?)
     }
 
-    // TODO: 주식정정취소가능주문조회[v1_국내주식-004]
-    // [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_d4537e9c-73f7-414c-9fb0-4eae3bc397d0)
+    /// 매도가능수량조회[국내주식-165]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock)
+    pub async fn inquire_psbl_sell(
+        &self,
+        pdno: &str,
+    ) -> Result<response::stock::order::Body::InquirePsblSell, Error> {
+        if let Environment::Virtual = self.environment {
+            return Err(Error::UnsupportedEnvironment(
+                self.environment.clone(),
+                "inquire_psbl_sell",
+            ));
+        }
+        let param = request::stock::order::InquirePsblSellParameter::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            pdno.to_string(),
+        );
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/trading/inquire-psbl-sell",
+            self.endpoint_url
+        );
+        let url = reqwest::Url::parse_with_params(&url, &param.into_iter())?;
+        Ok(self
+            .create_request(TrId::InquirePsblSell, url, false)?
+            .send()
+            .await?
+            .json::<response::stock::order::Body::InquirePsblSell>()
+            .await?)
+    }
+
+    /// 기간별손익일별합산조회[v1_국내주식-052]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock)
+    /// 기간 내 일별 매수/매도금액, 실현손익, 수수료, 제세금(실전투자 전용)
+    pub async fn inquire_period_profit(
+        &self,
+        start: &Time,
+        end: &Time,
+        pdno: &str,
+        sort_dvsn: SortClass,
+        ctx_area_fk100: &str,
+        ctx_area_nk100: &str,
+    ) -> Result<response::stock::order::Body::InquirePeriodProfit, Error> {
+        if let Environment::Virtual = self.environment {
+            return Err(Error::UnsupportedEnvironment(
+                self.environment.clone(),
+                "inquire_period_profit",
+            ));
+        }
+        let mut param = request::stock::order::InquirePeriodProfitParameter::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            start.date(),
+            end.date(),
+            pdno.to_string(),
+            sort_dvsn,
+        );
+        param
+            .set_ctx_area_fk100(ctx_area_fk100.to_string())
+            .set_ctx_area_nk100(ctx_area_nk100.to_string());
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/trading/inquire-period-profit",
+            self.endpoint_url
+        );
+        let url = reqwest::Url::parse_with_params(&url, &param.into_iter())?;
+        let response = self
+            .create_request(
+                TrId::InquirePeriodProfit,
+                url,
+                !ctx_area_nk100.trim().is_empty(),
+            )?
+            .send()
+            .await?;
+        let tr_cont = get_tr_cont(&response);
+        let mut body = response
+            .json::<response::stock::order::Body::InquirePeriodProfit>()
+            .await?;
+        body.set_tr_cont(tr_cont);
+        Ok(body)
+    }
+
+    /// 기간별손익일별합산조회[v1_국내주식-052]
+    /// 연속조회를 끝까지 반복해 전체 일별 손익과 기간 합계를 반환
+    pub async fn inquire_period_profit_all(
+        &self,
+        start: &Time,
+        end: &Time,
+        pdno: &str,
+        sort_dvsn: SortClass,
+    ) -> Result<
+        (
+            Vec<response::stock::order::Output::PeriodProfit>,
+            Option<response::stock::order::Output::PeriodProfitSummary>,
+        ),
+        Error,
+    > {
+        let mut days = vec![];
+        let mut summary = None;
+        self.fetch_all(
+            "inquire_period_profit",
+            |ctx_area_fk100, ctx_area_nk100| {
+                let sort_dvsn = sort_dvsn.clone();
+                async move {
+                    self.inquire_period_profit(
+                        start,
+                        end,
+                        pdno,
+                        sort_dvsn,
+                        &ctx_area_fk100,
+                        &ctx_area_nk100,
+                    )
+                    .await
+                }
+            },
+            |page| {
+                if let Some(output1) = page.output1() {
+                    days.extend(output1.iter().cloned());
+                }
+                if let Some(output2) = page.output2() {
+                    summary = Some(output2.clone());
+                }
+            },
+        )
+        .await?;
+        Ok((days, summary))
+    }
+
+    /// 기간별매매손익현황조회[v1_국내주식-060]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock)
+    /// 기간 내 일별 종목별 매매손익, 수수료, 제세금(실전투자 전용)
+    pub async fn inquire_period_trade_profit(
+        &self,
+        start: &Time,
+        end: &Time,
+        pdno: &str,
+        sort_dvsn: SortClass,
+        ctx_area_fk100: &str,
+        ctx_area_nk100: &str,
+    ) -> Result<response::stock::order::Body::InquirePeriodTradeProfit, Error> {
+        if let Environment::Virtual = self.environment {
+            return Err(Error::UnsupportedEnvironment(
+                self.environment.clone(),
+                "inquire_period_trade_profit",
+            ));
+        }
+        let mut param = request::stock::order::InquirePeriodProfitParameter::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            start.date(),
+            end.date(),
+            pdno.to_string(),
+            sort_dvsn,
+        );
+        param
+            .set_ctx_area_fk100(ctx_area_fk100.to_string())
+            .set_ctx_area_nk100(ctx_area_nk100.to_string());
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/trading/inquire-period-trade-profit",
+            self.endpoint_url
+        );
+        let url = reqwest::Url::parse_with_params(&url, &param.into_iter())?;
+        let response = self
+            .create_request(
+                TrId::InquirePeriodTradeProfit,
+                url,
+                !ctx_area_nk100.trim().is_empty(),
+            )?
+            .send()
+            .await?;
+        let tr_cont = get_tr_cont(&response);
+        let mut body = response
+            .json::<response::stock::order::Body::InquirePeriodTradeProfit>()
+            .await?;
+        body.set_tr_cont(tr_cont);
+        Ok(body)
+    }
+
+    /// 기간별매매손익현황조회[v1_국내주식-060]
+    /// 연속조회를 끝까지 반복해 전체 종목별 매매손익과 기간 합계를 반환
+    pub async fn inquire_period_trade_profit_all(
+        &self,
+        start: &Time,
+        end: &Time,
+        pdno: &str,
+        sort_dvsn: SortClass,
+    ) -> Result<
+        (
+            Vec<response::stock::order::Output::PeriodTradeProfit>,
+            Option<response::stock::order::Output::PeriodProfitSummary>,
+        ),
+        Error,
+    > {
+        let mut trades = vec![];
+        let mut summary = None;
+        self.fetch_all(
+            "inquire_period_trade_profit",
+            |ctx_area_fk100, ctx_area_nk100| {
+                let sort_dvsn = sort_dvsn.clone();
+                async move {
+                    self.inquire_period_trade_profit(
+                        start,
+                        end,
+                        pdno,
+                        sort_dvsn,
+                        &ctx_area_fk100,
+                        &ctx_area_nk100,
+                    )
+                    .await
+                }
+            },
+            |page| {
+                if let Some(output1) = page.output1() {
+                    trades.extend(output1.iter().cloned());
+                }
+                if let Some(output2) = page.output2() {
+                    summary = Some(output2.clone());
+                }
+            },
+        )
+        .await?;
+        Ok((trades, summary))
+    }
+
+    /// 주식잔고조회_실현손익[v1_국내주식-041]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock)
+    /// 주식잔고조회에 당일 실현손익이 추가된 조회(실전투자 전용)
+    /// 다음 페이지는 이전 응답의 ctx_area_fk100, ctx_area_nk100을 params.balance에 넣어 다시 호출
+    pub async fn inquire_balance_rlz_pl(
+        &self,
+        params: request::stock::order::InquireBalanceRlzPlParameter,
+    ) -> Result<response::stock::order::Body::InquireBalanceRlzPl, Error> {
+        if let Environment::Virtual = self.environment {
+            return Err(Error::UnsupportedEnvironment(
+                self.environment.clone(),
+                "inquire_balance_rlz_pl",
+            ));
+        }
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/trading/inquire-balance-rlz-pl",
+            self.endpoint_url
+        );
+        let url = reqwest::Url::parse_with_params(&url, &params.into_iter())?;
+        let response = self
+            .create_request(
+                TrId::InquireBalanceRlzPl,
+                url,
+                !params.balance().ctx_area_nk100().trim().is_empty(),
+            )?
+            .send()
+            .await?;
+        let tr_cont = get_tr_cont(&response);
+        let mut body = response
+            .json::<response::stock::order::Body::InquireBalanceRlzPl>()
+            .await?;
+        body.set_tr_cont(tr_cont);
+        Ok(body)
+    }
+
+    /// 투자계좌자산현황조회[v1_국내주식-048]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock)
+    /// 주식, 펀드, 채권, RP 등 자산구분별 평가금액과 계좌 합계(실전투자 전용)
+    pub async fn inquire_account_balance(
+        &self,
+    ) -> Result<response::stock::order::Body::InquireAccountBalance, Error> {
+        if let Environment::Virtual = self.environment {
+            return Err(Error::UnsupportedEnvironment(
+                self.environment.clone(),
+                "inquire_account_balance",
+            ));
+        }
+        let param = request::stock::order::InquireAccountBalanceParameter::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+        );
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/trading/inquire-account-balance",
+            self.endpoint_url
+        );
+        let url = reqwest::Url::parse_with_params(&url, &param.into_iter())?;
+        Ok(self
+            .create_request(TrId::InquireAccountBalance, url, false)?
+            .send()
+            .await?
+            .json::<response::stock::order::Body::InquireAccountBalance>()
+            .await?)
+    }
+
+    /// 주식예약주문[v1_국내주식-017]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock)
+    /// 장 운영시간 외에 접수해 다음 영업일 장 시작시 제출되는 주문(실전투자 전용)
+    /// rsvn_ord_end_dt를 지정하면 해당 일자까지의 기간예약주문
+    pub async fn reserve_order(
+        &self,
+        order_division: OrderClass,
+        order_direction: Direction,
+        pdno: &str,
+        qty: Quantity,
+        price: Price,
+        rsvn_ord_end_dt: Option<Time>,
+    ) -> Result<response::stock::order::Body::ReserveOrder, Error> {
+        if let Environment::Virtual = self.environment {
+            return Err(Error::UnsupportedEnvironment(
+                self.environment.clone(),
+                "reserve_order",
+            ));
+        }
+        check_reservation_order_class(&order_division)?;
+        let mut request = request::stock::order::Body::ReserveOrder::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            pdno.to_string(),
+            qty.clone(),
+            price,
+            order_direction.clone(),
+            order_division.clone(),
+        );
+        if let Some(end) = rsvn_ord_end_dt {
+            request.set_rsvn_ord_end_dt(end.date());
+        }
+        let request = request.get_json_string();
+        self.send_reservation(
+            TrId::ReserveOrder,
+            "/uapi/domestic-stock/v1/trading/order-resv",
+            request,
+            |id, time| JournalEntry::Reservation {
+                id,
+                time,
+                pdno: pdno.to_string(),
+                direction: order_direction,
+                order_class: order_division,
+                qty,
+                price,
+                correction_class: CorrectionClass::None,
+                rsvn_ord_seq: String::new(),
+            },
+            DryRun::respond_reservation,
+        )
+        .await
+    }
+
+    /// 주식예약주문정정취소[v1_국내주식-018,019]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock)
+    /// 주식예약주문조회 결과의 예약주문을 정정 또는 취소
+    pub async fn correct_reservation(
+        &self,
+        reservation: &response::stock::order::Output::InquireReserveOrder,
+        rvse_cncl_dvsn_cd: CorrectionClass,
+        order_division: OrderClass,
+        qty: Quantity,
+        price: Price,
+    ) -> Result<response::stock::order::Body::ReserveCorrection, Error> {
+        if let Environment::Virtual = self.environment {
+            return Err(Error::UnsupportedEnvironment(
+                self.environment.clone(),
+                "correct_reservation",
+            ));
+        }
+        check_reservation_order_class(&order_division)?;
+        let tr_id = match rvse_cncl_dvsn_cd {
+            CorrectionClass::Correction => TrId::ReserveOrderCorrection,
+            CorrectionClass::Cancel => TrId::ReserveOrderCancel,
+            CorrectionClass::None => {
+                return Err(Error::InvalidParameter(
+                    "rvse_cncl_dvsn_cd",
+                    "Correction or Cancel".to_string(),
+                ));
+            }
+        };
+        let direction: Direction = reservation.sll_buy_dvsn_cd().parse()?;
+        let mut request = request::stock::order::Body::ReserveCorrection::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            reservation.pdno().clone(),
+            qty.clone(),
+            price,
+            direction.clone(),
+            order_division.clone(),
+        );
+        request
+            .set_rsvn_ord_end_dt(reservation.rsvn_end_dt().clone())
+            .set_rsvn_ord_seq(reservation.rsvn_ord_seq().clone())
+            .set_rsvn_ord_orgno(reservation.ord_gno_brno().clone())
+            .set_rsvn_ord_ord_dt(reservation.rsvn_ord_ord_dt().clone());
+        let request = request.get_json_string();
+        self.send_reservation(
+            tr_id,
+            "/uapi/domestic-stock/v1/trading/order-resv-rvsecncl",
+            request,
+            |id, time| JournalEntry::Reservation {
+                id,
+                time,
+                pdno: reservation.pdno().clone(),
+                direction,
+                order_class: order_division,
+                qty,
+                price,
+                correction_class: rvse_cncl_dvsn_cd,
+                rsvn_ord_seq: reservation.rsvn_ord_seq().clone(),
+            },
+            DryRun::respond_reservation_correction,
+        )
+        .await
+    }
+
+    /// 예약주문/예약주문정정취소 전송. 주문과 같이 초당 전송 한도, journal, 드라이런 적용
+    async fn send_reservation<T: serde::de::DeserializeOwned + serde::Serialize>(
+        &self,
+        tr_id: TrId,
+        path: &str,
+        request: String,
+        intent: impl FnOnce(u64, String) -> JournalEntry,
+        respond: impl FnOnce(&DryRun, reqwest::Request) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        self.rate_limiter.acquire().await;
+        let journal_tr_id: String = tr_id.clone().into();
+        let builder = self
+            .create_post_request(
+                tr_id,
+                format!("{}{}", self.endpoint_url, path),
+                request.clone(),
+            )
+            .await?;
+        let id = self.journal_intent(intent)?;
+        match &self.dry_run {
+            Some(dry_run) => respond(dry_run, builder.build()?),
+            None => {
+                self.send_journaled(id, journal_tr_id, request, builder, reservation_response)
+                    .await
+            }
+        }
+    }
+
+    /// 주식예약주문정정취소[v1_국내주식-018,019]
+    /// 예약주문을 조회된 수량/단가 그대로 취소
+    pub async fn cancel_reservation(
+        &self,
+        reservation: &response::stock::order::Output::InquireReserveOrder,
+    ) -> Result<response::stock::order::Body::ReserveCorrection, Error> {
+        self.correct_reservation(
+            reservation,
+            CorrectionClass::Cancel,
+            reservation.ord_dvsn_cd().parse()?,
+            reservation.ord_rsvn_qty().parse()?,
+            reservation.ord_rsvn_unpr().parse()?,
+        )
+        .await
+    }
+
+    /// 주식예약주문조회[v1_국내주식-020]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock)
+    /// 다음 페이지는 이전 응답의 ctx_area_fk200, ctx_area_nk200을 넣어 다시 호출
+    pub async fn inquire_reserve_order(
+        &self,
+        start: &Time,
+        end: &Time,
+        prcs_dvsn_cd: ReservationProcessClass,
+        pdno: &str,
+        ctx_area_fk200: &str,
+        ctx_area_nk200: &str,
+    ) -> Result<response::stock::order::Body::InquireReserveOrder, Error> {
+        if let Environment::Virtual = self.environment {
+            return Err(Error::UnsupportedEnvironment(
+                self.environment.clone(),
+                "inquire_reserve_order",
+            ));
+        }
+        let mut param = request::stock::order::InquireReserveOrderParameter::new(
+            self.account.cano.clone(),
+            self.account.acnt_prdt_cd.clone(),
+            start.date(),
+            end.date(),
+            prcs_dvsn_cd,
+            pdno.to_string(),
+        );
+        param
+            .set_cncl_yn(true)
+            .set_ctx_area_fk200(ctx_area_fk200.to_string())
+            .set_ctx_area_nk200(ctx_area_nk200.to_string());
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/trading/order-resv-ccnl",
+            self.endpoint_url
+        );
+        let url = reqwest::Url::parse_with_params(&url, &param.into_iter())?;
+        let response = self
+            .create_request(
+                TrId::InquireReserveOrder,
+                url,
+                !ctx_area_nk200.trim().is_empty(),
+            )?
+            .send()
+            .await?;
+        let tr_cont = get_tr_cont(&response);
+        let mut body = response
+            .json::<response::stock::order::Body::InquireReserveOrder>()
+            .await?;
+        body.set_tr_cont(tr_cont);
+        Ok(body)
+    }
 
-    // TODO: 주식일별주문체결조회[v1_국내주식-005]
-    // [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_bc51f9f7-146f-4971-a5ae-ebd574acec12)
+    /// 주식예약주문조회[v1_국내주식-020]
+    /// 연속조회를 끝까지 반복해 기간 내 전체 예약주문을 반환
+    pub async fn inquire_reserve_order_all(
+        &self,
+        start: &Time,
+        end: &Time,
+        prcs_dvsn_cd: ReservationProcessClass,
+        pdno: &str,
+    ) -> Result<Vec<response::stock::order::Output::InquireReserveOrder>, Error> {
+        let mut result = vec![];
+        self.fetch_all(
+            "inquire_reserve_order",
+            |ctx_area_fk200, ctx_area_nk200| {
+                let prcs_dvsn_cd = prcs_dvsn_cd.clone();
+                async move {
+                    self.inquire_reserve_order(
+                        start,
+                        end,
+                        prcs_dvsn_cd,
+                        pdno,
+                        &ctx_area_fk200,
+                        &ctx_area_nk200,
+                    )
+                    .await
+                }
+            },
+            |page| {
+                if let Some(output) = page.output() {
+                    result.extend(output.iter().cloned());
+                }
+            },
+        )
+        .await?;
+        Ok(result)
+    }
 
-    // TODO: 주식잔고조회[v1_국내주식-006]
-    // [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_66c61080-674f-4c91-a0cc-db5e64e9a5e6)
+    /// 연속조회를 끝까지 반복하며 페이지마다 on_page를 호출
+    /// fetch는 이전 응답의 (연속조회검색조건, 연속조회키)로 한 페이지를 조회(첫 페이지는 공란)
+    async fn fetch_all<P, F, Fut>(
+        &self,
+        name: &'static str,
+        mut fetch: F,
+        mut on_page: impl FnMut(&P),
+    ) -> Result<(), Error>
+    where
+        P: Continued,
+        F: FnMut(String, String) -> Fut,
+        Fut: std::future::Future<Output = Result<P, Error>>,
+    {
+        let (mut fk, mut nk) = (String::new(), String::new());
+        loop {
+            self.rate_limiter.acquire().await;
+            let page = fetch(fk, nk).await?;
+            if let Some((msg_cd, msg1)) = page.failure() {
+                return Err(Error::BrokenProtocol(name, format!("{}: {}", msg_cd, msg1)));
+            }
+            on_page(&page);
+            if !page.has_next() {
+                return Ok(());
+            }
+            (fk, nk) = page.next_keys();
+        }
+    }
 
-    // TODO: 매수가능조회[v1_국내주식-007]
-    // [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_806e407c-3082-44c0-9d71-e8534db5ad54)
+    async fn create_post_request(
+        &self,
+        tr_id: TrId,
+        url: String,
+        request: String,
+    ) -> Result<reqwest::RequestBuilder, Error> {
+        let hash = self.auth.get_hash(request.clone()).await?;
+        Ok(self
+            .client
+            .post(url)
+            .header("Content-Type", "application/json")
+            .header(
+                "Authorization",
+                match self.auth.get_token() {
+                    Some(token) => format!("Bearer {}", token),
+                    None => {
+                        return Err(Error::AuthInitFailed("token"));
+                    }
+                },
+            )
+            .header("appkey", self.auth.get_appkey())
+            .header("appsecret", self.auth.get_appsecret())
+            .header("tr_id", Into::<String>::into(tr_id))
+            .header("hashkey", hash)
+            .header("custtype", "P")
+            .body(request))
+    }
+
+    fn create_request(
+        &self,
+        tr_id: TrId,
+        url: url::Url,
+        tr_cont: bool,
+    ) -> Result<reqwest::RequestBuilder, Error> {
+        Ok(self
+            .client
+            .get(url)
+            .header("Content-Type", "application/json")
+            .header(
+                "Authorization",
+                match self.auth.get_token() {
+                    Some(token) => format!("Bearer {}", token),
+                    None => {
+                        return Err(Error::AuthInitFailed("token"));
+                    }
+                },
+            )
+            .header("appkey", self.auth.get_appkey())
+            .header("appsecret", self.auth.get_appsecret())
+            .header("tr_id", Into::<String>::into(tr_id))
+            .header("tr_cont", if tr_cont { "N" } else { "" })
+            .header("custtype", "P"))
+    }
+}
+
+/// 응답 헤더의 연속 거래 여부(tr_cont)
+fn get_tr_cont(response: &reqwest::Response) -> String {
+    response
+        .headers()
+        .get("tr_cont")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// 예약주문은 지정가, 시장가, 조건부지정가, 장전시간외만 가능
+fn check_reservation_order_class(order_division: &OrderClass) -> Result<(), Error> {
+    match order_division {
+        OrderClass::Limit
+        | OrderClass::Market
+        | OrderClass::ConditionalLimit
+        | OrderClass::PreMarket => Ok(()),
+        _ => Err(Error::InvalidParameter(
+            "ord_dvsn_cd",
+            format!("{:?} is not allowed for reservation orders", order_division),
+        )),
+    }
+}
+
+/// 취소에 쓸 원주문의 주문구분(알 수 없으면 지정가)
+fn order_class(order: &request::stock::order::OrderRef) -> OrderClass {
+    order.order_class().clone().unwrap_or(OrderClass::Limit)
+}
+
+/// 주문/정정 전송. 연결 이후의 전송/응답 오류는 주문이 접수되었을 수 있으므로 Error::OrderUnconfirmed
+async fn send_order<T: serde::de::DeserializeOwned>(
+    request: reqwest::RequestBuilder,
+) -> Result<T, Error> {
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(e) if e.is_connect() => return Err(e.into()),
+        Err(e) => return Err(Error::OrderUnconfirmed(Box::new(e.into()))),
+    };
+    response
+        .json::<T>()
+        .await
+        .map_err(|e| Error::OrderUnconfirmed(Box::new(e.into())))
+}
+
+fn order_response(
+    id: u64,
+    time: String,
+    response: &response::stock::order::Body::Order,
+) -> JournalEntry {
+    JournalEntry::Response {
+        id,
+        time,
+        response: response.clone(),
+    }
+}
+
+fn reservation_response<T: serde::Serialize>(id: u64, time: String, response: &T) -> JournalEntry {
+    JournalEntry::ReservationResponse {
+        id,
+        time,
+        response: serde_json::to_value(response).unwrap_or_default(),
+    }
 }
@@ -1,13 +1,21 @@
-use crate::types::{request, response, Account, Environment, MarketCode, PeriodCode, TrId};
+use crate::stock::rate_limit::RateLimiter;
+use crate::types::{
+    request, response, stream, ChartPeriod, Environment, MarketCode, PeriodCode, Time, TrId,
+};
 use crate::{auth, Error};
 
+/// 연속 조회 시 초당 요청 한도 기본값(실전투자)
+const REAL_REQUESTS_PER_SEC: u32 = 20;
+/// 연속 조회 시 초당 요청 한도 기본값(모의투자)
+const VIRTUAL_REQUESTS_PER_SEC: u32 = 2;
+
 #[derive(Clone)]
 pub struct Quote {
     client: reqwest::Client,
     endpoint_url: String,
     environment: Environment,
     auth: auth::Auth,
-    account: Account,
+    rate_limiter: RateLimiter,
 }
 
 impl Quote {
@@ -17,22 +25,61 @@ impl Quote {
         client: &reqwest::Client,
         environment: Environment,
         auth: auth::Auth,
-        account: Account,
     ) -> Result<Self, Error> {
         let endpoint_url = match environment {
             Environment::Real => "https://openapi.koreainvestment.com:9443",
             Environment::Virtual => "https://openapivts.koreainvestment.com:29443",
         }
         .to_string();
+        let rate_limiter = RateLimiter::new(match environment {
+            Environment::Real => REAL_REQUESTS_PER_SEC,
+            Environment::Virtual => VIRTUAL_REQUESTS_PER_SEC,
+        });
         Ok(Self {
             client: client.clone(),
             endpoint_url,
             environment,
             auth,
-            account,
+            rate_limiter,
         })
     }
 
+    /// 연속 조회(_all) 시 초당 요청 수 한도 변경(기본값: 실전 20, 모의 2)
+    pub fn set_rate_limit(&mut self, per_sec: u32) -> Result<(), Error> {
+        if per_sec == 0 {
+            return Err(Error::InvalidParameter(
+                "per_sec",
+                "must be greater than 0".to_string(),
+            ));
+        }
+        self.rate_limiter = RateLimiter::new(per_sec);
+        Ok(())
+    }
+
+    /// 주식현재가 시세[v1_국내주식-008]
+    /// 주식/ETF/ETN은 MarketCode::Stock(J), NXT/통합 시세는 MarketCode::Nxt/Unified로 조회
+    pub async fn current_price(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+    ) -> Result<response::stock::quote::CurrentPriceResponse, Error> {
+        let tr_id = TrId::CurrentPrice;
+        let param =
+            request::stock::quote::CurrentPriceParameter::new(market_code, shortcode.to_string());
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/quotations/inquire-price",
+            self.endpoint_url
+        );
+        let params = param.into_iter();
+        let url = reqwest::Url::parse_with_params(&url, &params)?;
+        Ok(self
+            .create_request(tr_id, url)?
+            .send()
+            .await?
+            .json::<response::stock::quote::CurrentPriceResponse>()
+            .await?)
+    }
+
     /// 주식현재가 일자별[v1_국내주식-010]
     pub async fn daily_price(
         &self,
@@ -62,6 +109,425 @@ impl Quote {
             .await?)
     }
 
+    /// 주식현재가 호가/예상체결[v1_국내주식-011]
+    pub async fn asking_price_exp_ccn(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+    ) -> Result<response::stock::quote::AskingPriceExpCcnResponse, Error> {
+        let tr_id = TrId::AskingPriceExpCcn;
+        let param =
+            request::stock::quote::CurrentPriceParameter::new(market_code, shortcode.to_string());
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/quotations/inquire-asking-price-exp-ccn",
+            self.endpoint_url
+        );
+        let params = param.into_iter();
+        let url = reqwest::Url::parse_with_params(&url, &params)?;
+        Ok(self
+            .create_request(tr_id, url)?
+            .send()
+            .await?
+            .json::<response::stock::quote::AskingPriceExpCcnResponse>()
+            .await?)
+    }
+
+    /// 호가 스냅샷을 실시간 호가와 같은 구조로 조회
+    pub async fn order_book(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+    ) -> Result<stream::stock::ordb::Body, Error> {
+        self.asking_price_exp_ccn(market_code, shortcode)
+            .await?
+            .ordb()
+    }
+
+    /// 주식현재가 체결[v1_국내주식-009]
+    /// 최근 체결 30건
+    pub async fn ccnl(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+    ) -> Result<response::stock::quote::CcnlResponse, Error> {
+        let tr_id = TrId::Ccnl;
+        let param =
+            request::stock::quote::CurrentPriceParameter::new(market_code, shortcode.to_string());
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/quotations/inquire-ccnl",
+            self.endpoint_url
+        );
+        let params = param.into_iter();
+        let url = reqwest::Url::parse_with_params(&url, &params)?;
+        Ok(self
+            .create_request(tr_id, url)?
+            .send()
+            .await?
+            .json::<response::stock::quote::CcnlResponse>()
+            .await?)
+    }
+
+    /// 주식현재가 당일시간대별체결[v1_국내주식-023]
+    /// hour(HHMMSS) 이전 체결부터 최근 순으로 최대 30건
+    pub async fn time_item_conclusion(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        hour: &str,
+    ) -> Result<response::stock::quote::TimeItemConclusionResponse, Error> {
+        let tr_id = TrId::TimeItemConclusion;
+        let param = request::stock::quote::TimeItemConclusionParameter::new(
+            market_code,
+            shortcode.to_string(),
+            hour.to_string(),
+        );
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/quotations/inquire-time-itemconclusion",
+            self.endpoint_url
+        );
+        let params = param.into_iter();
+        let url = reqwest::Url::parse_with_params(&url, &params)?;
+        Ok(self
+            .create_request(tr_id, url)?
+            .send()
+            .await?
+            .json::<response::stock::quote::TimeItemConclusionResponse>()
+            .await?)
+    }
+
+    /// hour(HHMMSS)부터 장 시작까지 거슬러 올라가며 당일시간대별체결을 모두 조회하여
+    /// 실시간 체결가와 같은 구조로 반환(오래된 체결부터). 체결시각은 영업일자 date 기준
+    /// 페이지 경계는 누적 거래량으로 중복을 제거하며, 같은 초에 30건을 넘게 체결되면 일부가 빠질 수 있음
+    pub async fn time_item_conclusion_all(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        date: &Time,
+        hour: &str,
+    ) -> Result<Vec<stream::stock::exec::Body>, Error> {
+        let mut rows: Vec<response::stock::quote::output::TimeItemConclusion> = vec![];
+        let mut hour = hour.to_string();
+        let mut last_volume: Option<u64> = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let page = self
+                .time_item_conclusion(market_code.clone(), shortcode, &hour)
+                .await?;
+            if page.rt_cd() != "0" {
+                return Err(Error::BrokenProtocol(
+                    "time_item_conclusion",
+                    format!("{}: {}", page.msg_cd(), page.msg1()),
+                ));
+            }
+            let page = page.output2().clone().unwrap_or_default();
+            if page.is_empty() {
+                break;
+            }
+            let mut advanced = false;
+            for row in page {
+                let volume: u64 = response::stock::quote::number(row.acml_vol())?;
+                if last_volume.is_none_or(|last| volume < last) {
+                    last_volume = Some(volume);
+                    hour = row.stck_cntg_hour().clone();
+                    rows.push(row);
+                    advanced = true;
+                }
+            }
+            if !advanced {
+                match previous_second(&hour) {
+                    Some(previous) => hour = previous,
+                    None => break,
+                }
+            }
+        }
+        response::stock::quote::execs(
+            shortcode,
+            date,
+            rows.iter()
+                .map(response::stock::quote::output::TimeItemConclusion::tick),
+        )
+    }
+
+    /// 주식당일분봉조회[v1_국내주식-022]
+    /// hour(HHMMSS) 이전 1분봉부터 최근 순으로 최대 30건
+    pub async fn time_item_chart_price(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        hour: &str,
+        include_past_data: bool,
+    ) -> Result<response::stock::quote::MinuteChartPriceResponse, Error> {
+        let tr_id = TrId::TimeItemChartPrice;
+        let param = request::stock::quote::TimeItemChartPriceParameter::new(
+            market_code,
+            shortcode.to_string(),
+            hour.to_string(),
+            include_past_data,
+        );
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/quotations/inquire-time-itemchartprice",
+            self.endpoint_url
+        );
+        let params = param.into_iter();
+        let url = reqwest::Url::parse_with_params(&url, &params)?;
+        Ok(self
+            .create_request(tr_id, url)?
+            .send()
+            .await?
+            .json::<response::stock::quote::MinuteChartPriceResponse>()
+            .await?)
+    }
+
+    /// 주식일별분봉조회[국내주식-213]
+    /// date(YYYYMMDD)의 hour(HHMMSS) 이전 1분봉부터 최근 순으로 최대 120건
+    pub async fn time_daily_chart_price(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        date: &str,
+        hour: &str,
+        include_past_data: bool,
+    ) -> Result<response::stock::quote::MinuteChartPriceResponse, Error> {
+        if let Environment::Virtual = self.environment {
+            return Err(Error::UnsupportedEnvironment(
+                self.environment.clone(),
+                "time_daily_chart_price",
+            ));
+        }
+        let tr_id = TrId::TimeDailyChartPrice;
+        let param = request::stock::quote::TimeDailyChartPriceParameter::new(
+            market_code,
+            shortcode.to_string(),
+            date.to_string(),
+            hour.to_string(),
+            include_past_data,
+        );
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/quotations/inquire-time-dailychartprice",
+            self.endpoint_url
+        );
+        let params = param.into_iter();
+        let url = reqwest::Url::parse_with_params(&url, &params)?;
+        Ok(self
+            .create_request(tr_id, url)?
+            .send()
+            .await?
+            .json::<response::stock::quote::MinuteChartPriceResponse>()
+            .await?)
+    }
+
+    /// 당일 start~end(HHMMSS) 1분봉을 end부터 거슬러 올라가며 모두 조회(오래된 분봉부터)
+    /// include_pre_market이 false면 09:00 이전 분봉은 제외
+    pub async fn time_item_chart_price_all(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        start: &str,
+        end: &str,
+        include_pre_market: bool,
+    ) -> Result<Vec<response::stock::quote::output::MinuteChartPrice>, Error> {
+        self.minute_chart_all(None, start, end, include_pre_market, |hour| {
+            let market_code = market_code.clone();
+            async move {
+                self.time_item_chart_price(market_code, shortcode, &hour, false)
+                    .await
+            }
+        })
+        .await
+    }
+
+    /// date(YYYYMMDD)의 start~end(HHMMSS) 1분봉을 end부터 거슬러 올라가며 모두 조회(오래된 분봉부터)
+    /// include_pre_market이 false면 09:00 이전 분봉은 제외
+    pub async fn time_daily_chart_price_all(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        date: &str,
+        start: &str,
+        end: &str,
+        include_pre_market: bool,
+    ) -> Result<Vec<response::stock::quote::output::MinuteChartPrice>, Error> {
+        self.minute_chart_all(Some(date), start, end, include_pre_market, |hour| {
+            let market_code = market_code.clone();
+            async move {
+                self.time_daily_chart_price(market_code, shortcode, date, &hour, false)
+                    .await
+            }
+        })
+        .await
+    }
+
+    /// 분봉 연속 조회. date가 없으면 첫 응답의 영업일자 기준
+    async fn minute_chart_all<F, Fut>(
+        &self,
+        date: Option<&str>,
+        start: &str,
+        end: &str,
+        include_pre_market: bool,
+        mut fetch: F,
+    ) -> Result<Vec<response::stock::quote::output::MinuteChartPrice>, Error>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: std::future::Future<
+            Output = Result<response::stock::quote::MinuteChartPriceResponse, Error>,
+        >,
+    {
+        for (name, hour) in [("start", start), ("end", end)] {
+            if chrono::NaiveTime::parse_from_str(hour, "%H%M%S").is_err() {
+                return Err(Error::InvalidParameter(
+                    name,
+                    format!("{} is not HHMMSS", hour),
+                ));
+            }
+        }
+        if start > end {
+            return Err(Error::InvalidParameter(
+                "start",
+                format!("{} is later than {}", start, end),
+            ));
+        }
+        let mut date = date.map(str::to_string);
+        let mut rows: Vec<response::stock::quote::output::MinuteChartPrice> = vec![];
+        let mut hour = end.to_string();
+        loop {
+            self.rate_limiter.acquire().await;
+            let page = fetch(hour.clone()).await?;
+            if page.rt_cd() != "0" {
+                return Err(Error::BrokenProtocol(
+                    "minute_chart",
+                    format!("{}: {}", page.msg_cd(), page.msg1()),
+                ));
+            }
+            let mut advanced = false;
+            let mut done = false;
+            for row in page.output2().clone().unwrap_or_default() {
+                let date = date.get_or_insert_with(|| row.stck_bsop_date().clone());
+                if row.stck_bsop_date() != date || row.stck_cntg_hour().as_str() < start {
+                    done = true;
+                    break;
+                }
+                let older = rows
+                    .last()
+                    .is_none_or(|last| row.stck_cntg_hour() < last.stck_cntg_hour());
+                if older && row.stck_cntg_hour().as_str() <= end {
+                    hour = row.stck_cntg_hour().clone();
+                    rows.push(row);
+                    advanced = true;
+                }
+            }
+            if done || !advanced {
+                break;
+            }
+        }
+        rows.reverse();
+        if !include_pre_market {
+            rows.retain(|row| row.stck_cntg_hour().as_str() >= "090000");
+        }
+        Ok(rows)
+    }
+
+    /// 국내주식기간별시세(일/주/월/년)[v1_국내주식-016]
+    /// start~end(YYYYMMDD) 중 최근 순으로 최대 100건
+    pub async fn daily_item_chart_price(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        start: &str,
+        end: &str,
+        period: ChartPeriod,
+        is_adjust_price: bool,
+    ) -> Result<response::stock::quote::DailyItemChartPriceResponse, Error> {
+        let tr_id = TrId::DailyItemChartPrice;
+        let param = request::stock::quote::DailyItemChartPriceParameter::new(
+            market_code,
+            shortcode.to_string(),
+            start.to_string(),
+            end.to_string(),
+            period,
+            is_adjust_price,
+        );
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/quotations/inquire-daily-itemchartprice",
+            self.endpoint_url
+        );
+        let params = param.into_iter();
+        let url = reqwest::Url::parse_with_params(&url, &params)?;
+        Ok(self
+            .create_request(tr_id, url)?
+            .send()
+            .await?
+            .json::<response::stock::quote::DailyItemChartPriceResponse>()
+            .await?)
+    }
+
+    /// start~end(YYYYMMDD) 기간별시세를 end부터 100건씩 나눠 모두 조회(오래된 일자부터)
+    /// 다음 조회는 직전 응답의 가장 오래된 일자까지로 하고 겹치는 일자는 제외
+    pub async fn daily_item_chart_price_all(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        start: &str,
+        end: &str,
+        period: ChartPeriod,
+        is_adjust_price: bool,
+    ) -> Result<Vec<response::stock::quote::output::DailyItemChartPrice>, Error> {
+        for (name, date) in [("start", start), ("end", end)] {
+            if chrono::NaiveDate::parse_from_str(date, "%Y%m%d").is_err() {
+                return Err(Error::InvalidParameter(
+                    name,
+                    format!("{} is not YYYYMMDD", date),
+                ));
+            }
+        }
+        if start > end {
+            return Err(Error::InvalidParameter(
+                "start",
+                format!("{} is later than {}", start, end),
+            ));
+        }
+        let mut rows: Vec<response::stock::quote::output::DailyItemChartPrice> = vec![];
+        let mut end = end.to_string();
+        loop {
+            self.rate_limiter.acquire().await;
+            let page = self
+                .daily_item_chart_price(
+                    market_code.clone(),
+                    shortcode,
+                    start,
+                    &end,
+                    period.clone(),
+                    is_adjust_price,
+                )
+                .await?;
+            if page.rt_cd() != "0" {
+                return Err(Error::BrokenProtocol(
+                    "daily_item_chart_price",
+                    format!("{}: {}", page.msg_cd(), page.msg1()),
+                ));
+            }
+            let mut advanced = false;
+            for row in page.output2().clone().unwrap_or_default() {
+                let date = row.stck_bsop_date().as_str();
+                if date.is_empty() || date < start {
+                    continue;
+                }
+                if rows
+                    .last()
+                    .is_none_or(|last| date < last.stck_bsop_date().as_str())
+                {
+                    end = date.to_string();
+                    rows.push(row);
+                    advanced = true;
+                }
+            }
+            if !advanced || end.as_str() <= start {
+                break;
+            }
+        }
+        rows.reverse();
+        Ok(rows)
+    }
+
     /// 거래량순위[v1_국내주식-047]
     pub async fn volume_rank(
         &self,
@@ -160,3 +626,13 @@ impl Quote {
             .header("custtype", "P"))
     }
 }
+
+/// HHMMSS의 1초 전(000000이면 None)
+fn previous_second(hour: &str) -> Option<String> {
+    let time = chrono::NaiveTime::parse_from_str(hour.trim(), "%H%M%S").ok()?;
+    let previous = time.overflowing_sub_signed(chrono::Duration::seconds(1));
+    match previous.1 {
+        0 => Some(previous.0.format("%H%M%S").to_string()),
+        _ => None,
+    }
+}
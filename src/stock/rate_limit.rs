@@ -0,0 +1,31 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// 초당 요청 수 제한(요청 시작 시각을 일정 간격으로 배치)
+#[derive(Clone)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next: Arc<Mutex<Instant>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(per_sec: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / per_sec.max(1),
+            next: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// 다음 요청 가능 시각까지 대기
+    pub(crate) async fn acquire(&self) {
+        let at = {
+            let mut next = self.next.lock().await;
+            let at = (*next).max(Instant::now());
+            *next = at + self.interval;
+            at
+        };
+        tokio::time::sleep_until(at).await;
+    }
+}
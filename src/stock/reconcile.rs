@@ -0,0 +1,264 @@
+use crate::stock::order::Korea;
+use crate::stock::risk::RiskEngine;
+use crate::stock::tracker::{OrderTracker, TrackedOrder};
+use crate::types::{BalanceInquiryClass, Direction};
+use crate::Error;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// 로컬 기록과 잔고조회가 다른 항목
+#[derive(Debug, Clone, PartialEq)]
+pub enum Discrepancy {
+    /// 종목별 보유수량
+    Position {
+        pdno: String,
+        local: i64,
+        broker: i64,
+    },
+    /// D+2 예수금(원)
+    Cash { local: i64, broker: i64 },
+}
+
+/// 대사 결과 알림
+#[derive(Debug)]
+pub enum ReconcileEvent {
+    /// 불일치 발견. corrected가 true면 로컬 기준을 잔고조회에 맞춤
+    Mismatch {
+        discrepancy: Discrepancy,
+        corrected: bool,
+    },
+    /// 잔고조회 실패
+    Failed(String),
+}
+
+#[derive(Default)]
+struct State {
+    /// 종목별 기준 보유수량(잔고 - 추적 중인 순체결수량)
+    positions: HashMap<String, i64>,
+    /// 기준 D+2 예수금(예수금 - 추적 중인 순매매대금)
+    cash: i64,
+}
+
+/// 체결통보로 추정한 보유수량/예수금을 주기적으로 잔고조회와 대사
+/// 로컬 보유수량 = 기준 보유수량 + tracker의 순체결수량,
+/// 로컬 예수금 = 기준 예수금 + 매도 체결금액 - 매수 체결금액(제비용 제외)
+#[derive(Clone)]
+pub struct Reconciler {
+    korea: Korea,
+    tracker: OrderTracker,
+    risk: Option<RiskEngine>,
+    /// 예수금 허용 오차(수수료/세금 등, 원)
+    cash_tolerance: i64,
+    auto_correct: bool,
+    state: Arc<Mutex<State>>,
+    tx: mpsc::UnboundedSender<ReconcileEvent>,
+}
+
+impl Reconciler {
+    /// auto_correct가 true면 불일치 시 로컬 기준을 잔고조회에 맞추고
+    /// risk가 있으면 RiskEngine의 장 시작 전 보유수량도 함께 보정
+    pub fn new(
+        korea: &Korea,
+        tracker: &OrderTracker,
+        risk: Option<&RiskEngine>,
+        cash_tolerance: u64,
+        auto_correct: bool,
+    ) -> (Self, mpsc::UnboundedReceiver<ReconcileEvent>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        (
+            Self {
+                korea: korea.clone(),
+                tracker: tracker.clone(),
+                risk: risk.cloned(),
+                cash_tolerance: cash_tolerance as i64,
+                auto_correct,
+                state: Arc::new(Mutex::new(State::default())),
+                tx,
+            },
+            rx,
+        )
+    }
+
+    /// 현재 잔고조회 결과를 기준으로 로컬 상태 초기화
+    pub async fn init(&self) -> Result<(), Error> {
+        let (positions, cash) = self.broker().await?;
+        let orders = self.tracker.orders();
+        let mut state = self.state.lock().unwrap();
+        state.positions.clear();
+        for (pdno, qty) in positions.iter() {
+            let base = qty - net_qty(&orders, pdno);
+            state.positions.insert(pdno.clone(), base);
+        }
+        for pdno in traded(&orders) {
+            if !positions.contains_key(&pdno) {
+                let base = -net_qty(&orders, &pdno);
+                state.positions.insert(pdno, base);
+            }
+        }
+        state.cash = cash - net_cash(&orders);
+        if let Some(risk) = &self.risk {
+            for (pdno, base) in state.positions.iter() {
+                risk.set_position(pdno, (*base).max(0) as u64);
+            }
+        }
+        Ok(())
+    }
+
+    /// 로컬 종목별 보유수량
+    pub fn positions(&self) -> HashMap<String, i64> {
+        let orders = self.tracker.orders();
+        let state = self.state.lock().unwrap();
+        let mut symbols = state.positions.keys().cloned().collect::<BTreeSet<_>>();
+        symbols.extend(traded(&orders));
+        symbols
+            .into_iter()
+            .map(|pdno| {
+                let qty =
+                    state.positions.get(&pdno).copied().unwrap_or(0) + net_qty(&orders, &pdno);
+                (pdno, qty)
+            })
+            .collect()
+    }
+
+    /// 로컬 D+2 예수금
+    pub fn cash(&self) -> i64 {
+        self.state.lock().unwrap().cash + net_cash(&self.tracker.orders())
+    }
+
+    /// 잔고조회 한 번 대사 후 불일치 목록 반환(이벤트로도 전송)
+    /// 조회 도중 도착한 체결통보로 일시적인 불일치가 보고될 수 있음
+    pub async fn reconcile(&self) -> Result<Vec<Discrepancy>, Error> {
+        let (broker_positions, broker_cash) = match self.broker().await {
+            Ok(broker) => broker,
+            Err(e) => {
+                let _ = self.tx.send(ReconcileEvent::Failed(e.to_string()));
+                return Err(e);
+            }
+        };
+        let local_positions = self.positions();
+        let local_cash = self.cash();
+        let mut symbols = local_positions.keys().cloned().collect::<BTreeSet<_>>();
+        symbols.extend(broker_positions.keys().cloned());
+        let mut discrepancies = vec![];
+        for pdno in symbols {
+            let local = local_positions.get(&pdno).copied().unwrap_or(0);
+            let broker = broker_positions.get(&pdno).copied().unwrap_or(0);
+            if local != broker {
+                discrepancies.push(Discrepancy::Position {
+                    pdno,
+                    local,
+                    broker,
+                });
+            }
+        }
+        if (local_cash - broker_cash).abs() > self.cash_tolerance {
+            discrepancies.push(Discrepancy::Cash {
+                local: local_cash,
+                broker: broker_cash,
+            });
+        }
+        for discrepancy in discrepancies.iter() {
+            warn!("Reconcile mismatch: {:?}", discrepancy);
+            if self.auto_correct {
+                self.correct(discrepancy);
+            }
+            let _ = self.tx.send(ReconcileEvent::Mismatch {
+                discrepancy: discrepancy.clone(),
+                corrected: self.auto_correct,
+            });
+        }
+        Ok(discrepancies)
+    }
+
+    /// interval마다 대사
+    pub fn spawn(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let reconciler = self.clone();
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(interval);
+            loop {
+                tick.tick().await;
+                let _ = reconciler.reconcile().await;
+            }
+        })
+    }
+
+    fn correct(&self, discrepancy: &Discrepancy) {
+        let mut state = self.state.lock().unwrap();
+        match discrepancy {
+            Discrepancy::Position {
+                pdno,
+                local,
+                broker,
+            } => {
+                let base = state.positions.entry(pdno.clone()).or_default();
+                *base += broker - local;
+                if let Some(risk) = &self.risk {
+                    risk.set_position(pdno, (*base).max(0) as u64);
+                }
+            }
+            Discrepancy::Cash { local, broker } => state.cash += broker - local,
+        }
+    }
+
+    /// 잔고조회(종목별) 보유수량과 D+2 예수금
+    async fn broker(&self) -> Result<(HashMap<String, i64>, i64), Error> {
+        let (holdings, summary) = self
+            .korea
+            .inquire_balance_all(BalanceInquiryClass::ByStock, false, false, false)
+            .await?;
+        let mut positions = HashMap::new();
+        for holding in holdings {
+            let qty = holding.hldg_qty().trim().parse::<i64>().unwrap_or(0);
+            if qty != 0 {
+                *positions.entry(holding.pdno().clone()).or_default() += qty;
+            }
+        }
+        let cash = match summary {
+            Some(summary) => summary.prvs_rcdl_excc_amt().trim().parse::<i64>()?,
+            None => {
+                return Err(Error::BrokenProtocol(
+                    "Reconciler",
+                    "no account summary in balance".to_string(),
+                ));
+            }
+        };
+        Ok((positions, cash))
+    }
+}
+
+/// 체결이 있는 종목
+fn traded(orders: &[TrackedOrder]) -> BTreeSet<String> {
+    orders
+        .iter()
+        .filter(|order| order.filled_qty() > 0)
+        .map(|order| order.pdno().clone())
+        .collect()
+}
+
+/// 종목별 순체결수량(매수 +, 매도 -)
+fn net_qty(orders: &[TrackedOrder], pdno: &str) -> i64 {
+    orders
+        .iter()
+        .filter(|order| order.pdno() == pdno)
+        .map(|order| match order.direction() {
+            Direction::Bid => order.filled_qty() as i64,
+            Direction::Ask => -(order.filled_qty() as i64),
+        })
+        .sum()
+}
+
+/// 순매매대금(매도 +, 매수 -)
+fn net_cash(orders: &[TrackedOrder]) -> i64 {
+    orders
+        .iter()
+        .map(|order| {
+            let amount = (order.filled_qty() as f64 * order.avg_price()).round() as i64;
+            match order.direction() {
+                Direction::Bid => -amount,
+                Direction::Ask => amount,
+            }
+        })
+        .sum()
+}
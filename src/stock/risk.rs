@@ -0,0 +1,583 @@
+use crate::stock::tracker::{self, OrderTracker, TrackedOrder};
+use crate::types::{CorrectionClass, Direction, Price, Quantity};
+use crate::Error;
+use getset::{Getters, Setters};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// 주문 전 위험 한도(None이면 미적용)
+#[derive(Clone, Debug, Default, Getters, Setters)]
+pub struct RiskLimits {
+    /// 주문 1건당 최대 주문금액(원)
+    #[getset(get = "pub", set = "pub")]
+    max_order_notional: Option<u64>,
+    /// 일 최대 거래대금(체결금액 + 미체결 주문금액, 원)
+    #[getset(get = "pub", set = "pub")]
+    max_daily_gross: Option<u64>,
+    /// 종목별 최대 보유수량(매수 시 보유 + 체결 + 미체결 매수 기준)
+    #[getset(get = "pub", set = "pub")]
+    max_position: Option<u64>,
+    /// 최대 미체결 주문 수
+    #[getset(get = "pub", set = "pub")]
+    max_open_orders: Option<usize>,
+    /// 주문 허용 종목(None이면 전체 허용)
+    #[getset(get = "pub", set = "pub")]
+    allow_list: Option<HashSet<String>>,
+    /// 주문 금지 종목
+    #[getset(get = "pub", set = "pub")]
+    deny_list: HashSet<String>,
+    /// 일 최대 손실(원, 당일 매매분 실현 + 평가손익 기준)
+    #[getset(get = "pub", set = "pub")]
+    max_daily_loss: Option<u64>,
+}
+
+/// 점검을 통과했지만 아직 tracker에 등록되지 않은 주문
+struct Reserved {
+    pdno: String,
+    direction: Direction,
+    qty: u64,
+    notional: u64,
+}
+
+#[derive(Default)]
+struct State {
+    limits: RiskLimits,
+    killed: bool,
+    /// 종목별 장 시작 전 보유수량
+    positions: HashMap<String, u64>,
+    /// 종목별 기준가격(시장가 주문금액, 평가손익 계산용)
+    marks: HashMap<String, u32>,
+    /// 전송 중인 주문
+    reserved: HashMap<u64, Reserved>,
+    next_id: u64,
+}
+
+/// 전송 중인 주문의 한도 예약. drop하면 해제
+pub(crate) struct Reservation {
+    engine: RiskEngine,
+    id: u64,
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        self.engine.state.lock().unwrap().reserved.remove(&self.id);
+    }
+}
+
+/// 주문 전 위험 점검과 킬 스위치
+/// 체결/미체결 현황은 OrderTracker에서 가져오므로 같은 tracker를 Korea::set_tracker로 지정해야 함
+/// 일 거래대금, 미체결 주문 수, 손익은 당일(한국시간) 등록된 주문만 집계하며,
+/// 장 시작 전 보유수량은 영업일마다 set_position으로 다시 설정해야 함
+#[derive(Clone)]
+pub struct RiskEngine {
+    tracker: OrderTracker,
+    state: Arc<Mutex<State>>,
+}
+
+impl RiskEngine {
+    pub fn new(limits: RiskLimits, tracker: &OrderTracker) -> Self {
+        Self {
+            tracker: tracker.clone(),
+            state: Arc::new(Mutex::new(State {
+                limits,
+                ..Default::default()
+            })),
+        }
+    }
+
+    pub fn limits(&self) -> RiskLimits {
+        self.state.lock().unwrap().limits.clone()
+    }
+
+    pub fn set_limits(&self, limits: RiskLimits) {
+        self.state.lock().unwrap().limits = limits;
+    }
+
+    /// 장 시작 전 보유수량 설정(잔고조회 결과 등)
+    pub fn set_position(&self, pdno: &str, qty: u64) {
+        self.state
+            .lock()
+            .unwrap()
+            .positions
+            .insert(pdno.to_string(), qty);
+    }
+
+    /// 기준가격 갱신(실시간 체결가 등)
+    pub fn update_price(&self, pdno: &str, price: Price) {
+        self.state
+            .lock()
+            .unwrap()
+            .marks
+            .insert(pdno.to_string(), price.inner());
+    }
+
+    /// 킬 스위치 작동. 이후 신규/정정 주문은 거부되고 취소만 허용
+    pub fn engage_kill_switch(&self) {
+        warn!("Kill switch engaged");
+        self.state.lock().unwrap().killed = true;
+    }
+
+    pub fn release_kill_switch(&self) {
+        info!("Kill switch released");
+        self.state.lock().unwrap().killed = false;
+    }
+
+    pub fn is_killed(&self) -> bool {
+        self.state.lock().unwrap().killed
+    }
+
+    /// 당일 매매분 손익(매도금액 - 매수금액 + 순매수수량 평가금액)
+    pub fn daily_pnl(&self) -> i64 {
+        let state = self.state.lock().unwrap();
+        daily_pnl(&self.today_orders(), &state.marks)
+    }
+
+    /// 신규 주문 점검
+    pub fn check_order(
+        &self,
+        direction: &Direction,
+        pdno: &str,
+        qty: &Quantity,
+        price: &Price,
+    ) -> Result<(), Error> {
+        let state = self.state.lock().unwrap();
+        self.check(&state, direction, pdno, qty, price, None)?;
+        Ok(())
+    }
+
+    /// 신규 주문 점검 후 전송이 끝날 때까지 한도 예약
+    /// 동시에 점검하는 주문도 예약된 주문을 포함해 판단
+    pub(crate) fn reserve_order(
+        &self,
+        direction: &Direction,
+        pdno: &str,
+        qty: &Quantity,
+        price: &Price,
+    ) -> Result<Reservation, Error> {
+        let mut state = self.state.lock().unwrap();
+        let notional = self.check(&state, direction, pdno, qty, price, None)?;
+        let id = state.next_id;
+        state.next_id += 1;
+        state.reserved.insert(
+            id,
+            Reserved {
+                pdno: pdno.to_string(),
+                direction: direction.clone(),
+                qty: qty.inner() as u64,
+                notional,
+            },
+        );
+        Ok(Reservation {
+            engine: self.clone(),
+            id,
+        })
+    }
+
+    /// 당일 등록된 주문
+    fn today_orders(&self) -> Vec<TrackedOrder> {
+        let today = tracker::trading_date();
+        self.tracker
+            .orders()
+            .into_iter()
+            .filter(|order| order.trading_date() == &today)
+            .collect()
+    }
+
+    /// 점검 후 주문금액 반환
+    /// replacing은 정정 대상 주문으로, 그 미체결 잔량은 집계에서 제외
+    fn check(
+        &self,
+        state: &State,
+        direction: &Direction,
+        pdno: &str,
+        qty: &Quantity,
+        price: &Price,
+        replacing: Option<&TrackedOrder>,
+    ) -> Result<u64, Error> {
+        let limits = &state.limits;
+        if state.killed {
+            return Err(reject("kill switch is engaged"));
+        }
+        check_symbol(limits, pdno)?;
+        let unit_price = match price.inner() {
+            0 => match state.marks.get(pdno) {
+                Some(mark) => *mark,
+                None => {
+                    return Err(reject(&format!(
+                        "no reference price for {} to size a market order",
+                        pdno
+                    )));
+                }
+            },
+            price => price,
+        };
+        let notional = qty.inner() as u64 * unit_price as u64;
+        if let Some(max) = limits.max_order_notional {
+            if notional > max {
+                return Err(reject(&format!(
+                    "order notional {} exceeds {}",
+                    notional, max
+                )));
+            }
+        }
+        let orders = self.today_orders();
+        let replaced = |order: &TrackedOrder| {
+            replacing.is_some_and(|replacing| replacing.order_no() == order.order_no())
+        };
+        let open = orders
+            .iter()
+            .filter(|order| !order.state().is_terminal() && !replaced(order))
+            .count()
+            + state.reserved.len();
+        if let Some(max) = limits.max_open_orders {
+            if open >= max {
+                return Err(reject(&format!("{} open orders reached", max)));
+            }
+        }
+        if let Some(max) = limits.max_daily_gross {
+            let gross = orders
+                .iter()
+                .map(|order| {
+                    let filled = order.filled_qty() as f64 * order.avg_price();
+                    let remaining = if order.state().is_terminal() || replaced(order) {
+                        0
+                    } else {
+                        order.remaining_qty() * order_price(order, &state.marks) as u64
+                    };
+                    filled as u64 + remaining
+                })
+                .sum::<u64>()
+                + state
+                    .reserved
+                    .values()
+                    .map(|reserved| reserved.notional)
+                    .sum::<u64>();
+            if gross + notional > max {
+                return Err(reject(&format!(
+                    "daily gross {} would exceed {}",
+                    gross + notional,
+                    max
+                )));
+            }
+        }
+        if let (Some(max), Direction::Bid) = (limits.max_position, direction) {
+            let mut position = state.positions.get(pdno).copied().unwrap_or(0) as i64;
+            for order in orders.iter().filter(|order| order.pdno() == pdno) {
+                match order.direction() {
+                    Direction::Bid => {
+                        position += order.filled_qty() as i64;
+                        if !order.state().is_terminal() && !replaced(order) {
+                            position += order.remaining_qty() as i64;
+                        }
+                    }
+                    Direction::Ask => position -= order.filled_qty() as i64,
+                }
+            }
+            for reserved in state.reserved.values() {
+                if reserved.pdno == pdno && reserved.direction == Direction::Bid {
+                    position += reserved.qty as i64;
+                }
+            }
+            let position = position + qty.inner() as i64;
+            if position > max as i64 {
+                return Err(reject(&format!(
+                    "position {} in {} would exceed {}",
+                    position, pdno, max
+                )));
+            }
+        }
+        if let Some(max) = limits.max_daily_loss {
+            let pnl = daily_pnl(&orders, &state.marks);
+            if pnl <= -(max as i64) {
+                return Err(reject(&format!(
+                    "daily loss {} reached limit {}",
+                    -pnl, max
+                )));
+            }
+        }
+        Ok(notional)
+    }
+
+    /// 정정/취소 주문 점검. 취소는 항상 허용하고, 추적하지 않는 주문의 정정은 거부
+    /// 수량이나 단가를 올리는 정정은 신규 주문과 같이 거래대금/보유수량/미체결 주문 수 한도를 점검
+    pub fn check_correction(
+        &self,
+        orgn_odno: &str,
+        correction_class: &CorrectionClass,
+        qty_all_ord_yn: bool,
+        qty: &Quantity,
+        price: &Price,
+    ) -> Result<(), Error> {
+        if let CorrectionClass::Cancel = correction_class {
+            return Ok(());
+        }
+        let state = self.state.lock().unwrap();
+        if state.killed {
+            return Err(reject("kill switch is engaged"));
+        }
+        let order = match orgn_odno
+            .trim()
+            .parse()
+            .ok()
+            .and_then(|no| self.tracker.get(no))
+        {
+            Some(order) => order,
+            None => return Err(reject(&format!("order {} is not tracked", orgn_odno))),
+        };
+        let qty = if qty_all_ord_yn {
+            order.remaining_qty()
+        } else {
+            qty.inner() as u64
+        };
+        let qty =
+            Quantity::from(u32::try_from(qty).map_err(|_| {
+                Error::InvalidParameter("ord_qty", format!("{} is out of range", qty))
+            })?);
+        let raised = qty.inner() as u64 > order.remaining_qty()
+            || price.inner() > order_price(&order, &state.marks);
+        if raised {
+            self.check(
+                &state,
+                order.direction(),
+                order.pdno(),
+                &qty,
+                price,
+                Some(&order),
+            )?;
+            return Ok(());
+        }
+        check_symbol(&state.limits, order.pdno())?;
+        if let Some(max) = state.limits.max_order_notional {
+            let notional = qty.inner() as u64 * price.inner() as u64;
+            if notional > max {
+                return Err(reject(&format!(
+                    "order notional {} exceeds {}",
+                    notional, max
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn reject(reason: &str) -> Error {
+    Error::RiskRejected(reason.to_string())
+}
+
+fn check_symbol(limits: &RiskLimits, pdno: &str) -> Result<(), Error> {
+    if limits.deny_list.contains(pdno) {
+        return Err(reject(&format!("{} is in the deny list", pdno)));
+    }
+    if let Some(allow_list) = &limits.allow_list {
+        if !allow_list.contains(pdno) {
+            return Err(reject(&format!("{} is not in the allow list", pdno)));
+        }
+    }
+    Ok(())
+}
+
+/// 미체결 주문의 단가(시장가 주문은 기준가격)
+fn order_price(order: &TrackedOrder, marks: &HashMap<String, u32>) -> u32 {
+    match order.order_price() {
+        0 => marks.get(order.pdno()).copied().unwrap_or(0),
+        price => price,
+    }
+}
+
+fn daily_pnl(orders: &[TrackedOrder], marks: &HashMap<String, u32>) -> i64 {
+    let mut cash = HashMap::<&str, f64>::new();
+    let mut qty = HashMap::<&str, i64>::new();
+    let mut last = HashMap::<&str, f64>::new();
+    for order in orders.iter().filter(|order| order.filled_qty() > 0) {
+        let value = order.filled_qty() as f64 * order.avg_price();
+        let (sign, cash_sign) = match order.direction() {
+            Direction::Bid => (1, -1.0),
+            Direction::Ask => (-1, 1.0),
+        };
+        *cash.entry(order.pdno()).or_default() += cash_sign * value;
+        *qty.entry(order.pdno()).or_default() += sign * order.filled_qty() as i64;
+        last.insert(order.pdno(), order.avg_price());
+    }
+    cash.iter()
+        .map(|(pdno, cash)| {
+            let mark = marks
+                .get(*pdno)
+                .map(|mark| *mark as f64)
+                .unwrap_or(last[pdno]);
+            cash + qty[pdno] as f64 * mark
+        })
+        .sum::<f64>() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stock::journal::ExecRecord;
+    use crate::stock::testing::{self, register, PDNO};
+
+    fn fill(tracker: &OrderTracker, order_no: u64, direction: Direction, qty: u64) {
+        let record = ExecRecord {
+            ask_bid_class: direction,
+            ..testing::fill(order_no, qty, 1_000)
+        };
+        testing::apply(tracker, record);
+    }
+
+    fn engine(limits: RiskLimits) -> (OrderTracker, RiskEngine) {
+        let tracker = OrderTracker::new();
+        let risk = RiskEngine::new(limits, &tracker);
+        (tracker, risk)
+    }
+
+    fn bid(risk: &RiskEngine, qty: u32, price: u32) -> Result<(), Error> {
+        risk.check_order(
+            &Direction::Bid,
+            PDNO,
+            &Quantity::from(qty),
+            &Price::from(price),
+        )
+    }
+
+    fn reserve(risk: &RiskEngine, qty: u32) -> Result<Reservation, Error> {
+        risk.reserve_order(
+            &Direction::Bid,
+            PDNO,
+            &Quantity::from(qty),
+            &Price::from(1_000),
+        )
+    }
+
+    #[test]
+    fn kill_switch_allows_cancel_only() {
+        let (_, risk) = engine(RiskLimits::default());
+        assert!(bid(&risk, 1, 1_000).is_ok());
+        risk.engage_kill_switch();
+        assert!(bid(&risk, 1, 1_000).is_err());
+        let (qty, price) = (Quantity::from(0), Price::from(0));
+        assert!(risk
+            .check_correction("1", &CorrectionClass::Cancel, true, &qty, &price)
+            .is_ok());
+        assert!(risk
+            .check_correction("1", &CorrectionClass::Correction, true, &qty, &price)
+            .is_err());
+        risk.release_kill_switch();
+        assert!(bid(&risk, 1, 1_000).is_ok());
+    }
+
+    #[test]
+    fn symbol_lists() {
+        let mut limits = RiskLimits::default();
+        limits.set_deny_list(HashSet::from([PDNO.to_string()]));
+        let (_, risk) = engine(limits.clone());
+        assert!(bid(&risk, 1, 1_000).is_err());
+        limits.set_deny_list(HashSet::new());
+        limits.set_allow_list(Some(HashSet::from(["000660".to_string()])));
+        risk.set_limits(limits);
+        assert!(bid(&risk, 1, 1_000).is_err());
+    }
+
+    #[test]
+    fn order_notional_and_market_price() {
+        let mut limits = RiskLimits::default();
+        limits.set_max_order_notional(Some(10_000));
+        let (_, risk) = engine(limits);
+        assert!(bid(&risk, 10, 1_000).is_ok());
+        assert!(bid(&risk, 11, 1_000).is_err());
+        // 기준가격이 없으면 시장가 주문금액을 알 수 없음
+        assert!(bid(&risk, 1, 0).is_err());
+        risk.update_price(PDNO, Price::from(2_000));
+        assert!(bid(&risk, 5, 0).is_ok());
+        assert!(bid(&risk, 6, 0).is_err());
+    }
+
+    #[test]
+    fn open_orders_include_reservations() {
+        let mut limits = RiskLimits::default();
+        limits.set_max_open_orders(Some(2));
+        let (tracker, risk) = engine(limits);
+        register(&tracker, 1, Direction::Bid, 1);
+        let reservation = reserve(&risk, 1).unwrap();
+        assert!(reserve(&risk, 1).is_err());
+        drop(reservation);
+        let _reservation = reserve(&risk, 1).unwrap();
+        fill(&tracker, 1, Direction::Bid, 1);
+        assert!(bid(&risk, 1, 1_000).is_ok());
+    }
+
+    #[test]
+    fn daily_gross() {
+        let mut limits = RiskLimits::default();
+        limits.set_max_daily_gross(Some(30_000));
+        let (tracker, risk) = engine(limits);
+        register(&tracker, 1, Direction::Bid, 10);
+        fill(&tracker, 1, Direction::Bid, 4);
+        // 체결 4,000 + 미체결 6,000
+        let _reservation = reserve(&risk, 10).unwrap();
+        assert!(bid(&risk, 10, 1_000).is_ok());
+        assert!(bid(&risk, 11, 1_000).is_err());
+    }
+
+    #[test]
+    fn position() {
+        let mut limits = RiskLimits::default();
+        limits.set_max_position(Some(20));
+        let (tracker, risk) = engine(limits);
+        risk.set_position(PDNO, 5);
+        register(&tracker, 1, Direction::Bid, 5);
+        register(&tracker, 2, Direction::Ask, 3);
+        fill(&tracker, 2, Direction::Ask, 3);
+        // 5 + 5 - 3
+        let _reservation = reserve(&risk, 4).unwrap();
+        assert!(bid(&risk, 9, 1_000).is_ok());
+        assert!(bid(&risk, 10, 1_000).is_err());
+        let ask = risk.check_order(
+            &Direction::Ask,
+            PDNO,
+            &Quantity::from(100),
+            &Price::from(1_000),
+        );
+        assert!(ask.is_ok());
+    }
+
+    #[test]
+    fn correction() {
+        let mut limits = RiskLimits::default();
+        limits.set_max_open_orders(Some(1));
+        limits.set_max_daily_gross(Some(25_000));
+        limits.set_max_position(Some(20));
+        let (tracker, risk) = engine(limits);
+        register(&tracker, 1, Direction::Bid, 10);
+        let correct = |odno: &str, qty: u32, price: u32| {
+            risk.check_correction(
+                odno,
+                &CorrectionClass::Correction,
+                false,
+                &Quantity::from(qty),
+                &Price::from(price),
+            )
+        };
+        assert!(correct("0000000009", 10, 1_000).is_err());
+        // 정정 대상 주문은 미체결 주문 수와 잔량 집계에서 제외
+        assert!(bid(&risk, 1, 1_000).is_err());
+        assert!(correct("0000000001", 5, 1_000).is_ok());
+        assert!(correct("0000000001", 20, 1_000).is_ok());
+        assert!(correct("0000000001", 21, 1_000).is_err());
+        assert!(correct("0000000001", 8, 3_000).is_ok());
+        assert!(correct("0000000001", 10, 3_000).is_err());
+    }
+
+    #[test]
+    fn daily_loss() {
+        let mut limits = RiskLimits::default();
+        limits.set_max_daily_loss(Some(1_000));
+        let (tracker, risk) = engine(limits);
+        register(&tracker, 1, Direction::Bid, 10);
+        fill(&tracker, 1, Direction::Bid, 10);
+        risk.update_price(PDNO, Price::from(950));
+        assert_eq!(risk.daily_pnl(), -500);
+        assert!(bid(&risk, 1, 1_000).is_ok());
+        risk.update_price(PDNO, Price::from(900));
+        assert_eq!(risk.daily_pnl(), -1_000);
+        assert!(bid(&risk, 1, 1_000).is_err());
+    }
+}
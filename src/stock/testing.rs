@@ -0,0 +1,68 @@
+//! 단위 테스트용 주문 응답과 체결통보
+use crate::stock::journal::ExecRecord;
+use crate::stock::tracker::OrderTracker;
+use crate::types::request::stock::order::OrderRequest;
+use crate::types::response::stock::order::{Body, Output};
+use crate::types::{AcceptClass, CorrectionClass, Direction, OrderClass, Price, Quantity};
+
+pub(crate) const PDNO: &str = "005930";
+
+/// 성공한 주문 응답
+pub(crate) fn response(order_no: u64) -> Body::Order {
+    Body::Order::new(
+        "0".to_string(),
+        String::new(),
+        String::new(),
+        Some(Output::Order::new(
+            "00950".to_string(),
+            format!("{:010}", order_no),
+            "090000".to_string(),
+        )),
+    )
+}
+
+/// PDNO를 1,000원에 qty만큼 주문한 것으로 등록
+pub(crate) fn register(tracker: &OrderTracker, order_no: u64, direction: Direction, qty: u32) {
+    let request = OrderRequest::new(
+        direction,
+        PDNO,
+        OrderClass::Limit,
+        Quantity::from(qty),
+        Price::from(1_000),
+        None,
+    );
+    tracker.register(&request, &response(order_no)).unwrap();
+}
+
+/// 매수 10주 주문의 접수 통보
+pub(crate) fn notice(order_no: u64) -> ExecRecord {
+    ExecRecord {
+        order_no,
+        origin_order_no: 0,
+        ask_bid_class: Direction::Bid,
+        correction_class: CorrectionClass::None,
+        order_kind: OrderClass::Limit,
+        shortcode: PDNO.to_string(),
+        exec_qty: 0,
+        exec_price: 0,
+        exec_time: "20240102090000".to_string(),
+        is_refused: false,
+        is_executed: false,
+        accept_class: AcceptClass::Received,
+        order_qty: 10,
+    }
+}
+
+/// 체결 통보
+pub(crate) fn fill(order_no: u64, qty: u64, price: u32) -> ExecRecord {
+    ExecRecord {
+        is_executed: true,
+        exec_qty: qty,
+        exec_price: price,
+        ..notice(order_no)
+    }
+}
+
+pub(crate) fn apply(tracker: &OrderTracker, record: ExecRecord) {
+    tracker.apply_body(record.into_body().unwrap());
+}
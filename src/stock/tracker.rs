@@ -0,0 +1,656 @@
+use crate::stock::journal::{self, Journal, JournalEntry};
+use crate::types::request::stock::order::{OrderRef, OrderRequest};
+use crate::types::response::stock::order::{Body, Output};
+use crate::types::stream::stock::{my_exec, MyExec};
+use crate::types::{AcceptClass, CorrectionClass, Direction, Exchange, OrderClass, Price, Time};
+use crate::Error;
+use getset::{CopyGetters, Getters};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::watch;
+
+/// 등록 전 체결통보를 보관할 최대 주문번호 수
+const MAX_PENDING: usize = 1024;
+
+/// 주문 상태
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OrderState {
+    /// 제출(체결통보 수신 전)
+    New,
+    /// 접수
+    Accepted,
+    /// 일부체결
+    PartiallyFilled,
+    /// 전량체결
+    Filled,
+    /// 취소(일부체결 후 잔량 취소 포함)
+    Cancelled,
+    /// 거부
+    Rejected,
+}
+
+impl OrderState {
+    /// 더 이상 상태가 바뀌지 않는지 여부
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Filled | Self::Cancelled | Self::Rejected)
+    }
+}
+
+/// 추적 중인 주문
+/// 정정되어 주문번호가 바뀌어도 최초 주문번호로 계속 추적
+#[derive(Clone, Debug, Getters, CopyGetters)]
+pub struct TrackedOrder {
+    /// 최초 주문번호
+    #[getset(get_copy = "pub")]
+    order_no: u64,
+    /// 현재 유효한 주문번호(정정 시 갱신)
+    #[getset(get_copy = "pub")]
+    current_order_no: u64,
+    /// 한국거래소전송주문조직번호
+    #[getset(get = "pub")]
+    krx_fwdg_ord_orgno: String,
+    /// 종목코드
+    #[getset(get = "pub")]
+    pdno: String,
+    /// 매도매수구분
+    #[getset(get = "pub")]
+    direction: Direction,
+    /// 주문구분
+    #[getset(get = "pub")]
+    order_class: OrderClass,
+    /// 거래소(None이면 기본값)
+    #[getset(get_copy = "pub")]
+    exchange: Option<Exchange>,
+    /// 주문수량(정정 시 체결수량 + 정정수량)
+    #[getset(get_copy = "pub")]
+    order_qty: u64,
+    /// 주문단가
+    #[getset(get_copy = "pub")]
+    order_price: u32,
+    /// 상태
+    #[getset(get_copy = "pub")]
+    state: OrderState,
+    /// 누적 체결수량
+    #[getset(get_copy = "pub")]
+    filled_qty: u64,
+    /// 평균 체결단가
+    #[getset(get_copy = "pub")]
+    avg_price: f64,
+    /// 마지막 체결통보 시각
+    #[getset(get = "pub")]
+    updated_at: Option<Time>,
+    /// 등록한 영업일(YYYYMMDD, 한국시간)
+    #[getset(get = "pub")]
+    trading_date: String,
+}
+
+impl TrackedOrder {
+    /// 미체결 잔량
+    pub fn remaining_qty(&self) -> u64 {
+        self.order_qty.saturating_sub(self.filled_qty)
+    }
+
+    /// 현재 유효한 주문번호로 정정/취소 대상 주문 생성
+    pub fn order_ref(&self) -> OrderRef {
+        let order = OrderRef::new(
+            &self.krx_fwdg_ord_orgno,
+            &format!("{:010}", self.current_order_no),
+        )
+        .with_order(&self.pdno, self.direction.clone())
+        .with_order_class(self.order_class.clone());
+        match self.exchange {
+            Some(exchange) => order.with_exchange(exchange),
+            None => order,
+        }
+    }
+
+    fn apply(&mut self, body: &my_exec::Body) {
+        self.updated_at = Some(body.exec_time.clone());
+        // 취소와 경합한 체결은 종료 상태여도 반영
+        if body.is_executed {
+            let filled = self.filled_qty + body.exec_qty;
+            self.avg_price = (self.avg_price * self.filled_qty as f64
+                + body.exec_price as f64 * body.exec_qty as f64)
+                / filled as f64;
+            self.filled_qty = filled;
+            if self.filled_qty >= self.order_qty {
+                self.state = OrderState::Filled;
+            } else if !self.state.is_terminal() {
+                self.state = OrderState::PartiallyFilled;
+            }
+            return;
+        }
+        if self.state.is_terminal() {
+            return;
+        }
+        match body.correction_class {
+            CorrectionClass::None => {
+                if body.is_refused {
+                    self.state = OrderState::Rejected;
+                } else if body.accept_class == AcceptClass::AutoCancelled {
+                    self.state = OrderState::Cancelled;
+                } else if self.state == OrderState::New {
+                    self.state = OrderState::Accepted;
+                }
+            }
+            // 정정/취소 거부는 원주문 상태를 바꾸지 않음
+            _ if body.is_refused => {}
+            CorrectionClass::Correction => {
+                self.current_order_no = body.order_no;
+                self.order_qty = self.filled_qty + body.order_qty;
+                if body.exec_price > 0 {
+                    self.order_price = body.exec_price;
+                }
+                if self.state == OrderState::New {
+                    self.state = OrderState::Accepted;
+                }
+            }
+            CorrectionClass::Cancel => {
+                if body.order_qty >= self.remaining_qty() {
+                    self.state = OrderState::Cancelled;
+                } else {
+                    self.order_qty -= body.order_qty;
+                }
+            }
+        }
+    }
+}
+
+struct Entry {
+    order: TrackedOrder,
+    tx: watch::Sender<TrackedOrder>,
+}
+
+#[derive(Default)]
+struct Inner {
+    orders: HashMap<u64, Entry>,
+    /// 정정/취소 주문번호 -> 최초 주문번호
+    aliases: HashMap<u64, u64>,
+    /// 등록 전에 도착한 체결통보
+    pending: HashMap<u64, Vec<my_exec::Body>>,
+    journal: Option<Journal>,
+}
+
+impl Inner {
+    fn resolve(&self, order_no: u64) -> Option<u64> {
+        if self.orders.contains_key(&order_no) {
+            Some(order_no)
+        } else {
+            self.aliases.get(&order_no).copied()
+        }
+    }
+
+    fn apply(&mut self, body: my_exec::Body) {
+        let key = self.resolve(body.order_no).or_else(|| {
+            if body.correction_class == CorrectionClass::None {
+                None
+            } else {
+                self.resolve(body.origin_order_no)
+            }
+        });
+        let Some(key) = key else {
+            self.pending.entry(body.order_no).or_default().push(body);
+            // 추적하지 않는 주문(HTS 등)의 체결통보는 오래된 주문번호부터 버림
+            while self.pending.len() > MAX_PENDING {
+                let oldest = *self.pending.keys().min().unwrap();
+                self.pending.remove(&oldest);
+            }
+            return;
+        };
+        if body.order_no != key {
+            self.aliases.insert(body.order_no, key);
+        }
+        let entry = self.orders.get_mut(&key).unwrap();
+        entry.order.apply(&body);
+        entry.tx.send_replace(entry.order.clone());
+    }
+}
+
+/// 체결통보(MyExec)로 주문 상태를 추적
+#[derive(Clone, Default)]
+pub struct OrderTracker {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl OrderTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 제출한 주문 등록. 주문번호를 반환
+    /// 주문이 실패했으면(rt_cd != "0") 등록하지 않고 에러 반환
+    pub fn register(&self, request: &OrderRequest, response: &Body::Order) -> Result<u64, Error> {
+        let order_no = parse_order_no(response)?;
+        let krx_fwdg_ord_orgno = response
+            .output()
+            .as_ref()
+            .map(|output| output.krx_fwdg_ord_orgno().clone())
+            .unwrap_or_default();
+        self.restore(order_no, &krx_fwdg_ord_orgno, request);
+        Ok(order_no)
+    }
+
+    /// 주문번호와 주문 내용으로 등록(기록 복구용)
+    pub(crate) fn restore(&self, order_no: u64, krx_fwdg_ord_orgno: &str, request: &OrderRequest) {
+        let order = TrackedOrder {
+            order_no,
+            current_order_no: order_no,
+            krx_fwdg_ord_orgno: krx_fwdg_ord_orgno.to_string(),
+            pdno: request.pdno().clone(),
+            direction: request.direction().clone(),
+            order_class: request.order_class().clone(),
+            exchange: *request.exchange(),
+            order_qty: request.qty().inner() as u64,
+            order_price: request.price().inner(),
+            state: OrderState::New,
+            filled_qty: 0,
+            avg_price: 0.0,
+            updated_at: None,
+            trading_date: trading_date(),
+        };
+        let mut inner = self.inner.lock().unwrap();
+        let (tx, _) = watch::channel(order.clone());
+        inner.orders.insert(order_no, Entry { order, tx });
+        let mut pending = inner.pending.remove(&order_no).unwrap_or_default();
+        let aliased = inner
+            .pending
+            .iter()
+            .filter(|(_, bodies)| bodies.iter().any(|b| b.origin_order_no == order_no))
+            .map(|(no, _)| *no)
+            .collect::<Vec<_>>();
+        for no in aliased {
+            pending.extend(inner.pending.remove(&no).unwrap_or_default());
+        }
+        pending.sort_by_key(|b| b.exec_time.inner());
+        for body in pending {
+            inner.apply(body);
+        }
+    }
+
+    /// 정정/취소 주문 결과 반영
+    /// 성공한 경우 새 주문번호를 최초 주문에 연결하고 정정은 주문번호/단가를 갱신
+    /// 취소 상태(일부취소 시 주문수량 감소 포함)는 거래소 확인 체결통보로 반영
+    pub fn apply_correction(
+        &self,
+        order_no: u64,
+        correction_class: CorrectionClass,
+        price: Price,
+        response: &Body::Order,
+    ) -> Result<(), Error> {
+        let new_order_no = parse_order_no(response)?;
+        let mut inner = self.inner.lock().unwrap();
+        let key = inner
+            .resolve(order_no)
+            .ok_or_else(|| Error::InvalidParameter("order_no", order_no.to_string()))?;
+        inner.aliases.insert(new_order_no, key);
+        let entry = inner.orders.get_mut(&key).unwrap();
+        if entry.order.state.is_terminal() {
+            return Ok(());
+        }
+        if let CorrectionClass::Correction = correction_class {
+            entry.order.current_order_no = new_order_no;
+            if price.inner() > 0 {
+                entry.order.order_price = price.inner();
+            }
+        }
+        entry.tx.send_replace(entry.order.clone());
+        Ok(())
+    }
+
+    /// 체결통보를 journal에도 기록
+    pub fn set_journal(&self, journal: Option<&Journal>) {
+        self.inner.lock().unwrap().journal = journal.cloned();
+    }
+
+    /// 체결통보 반영. 등록되지 않은 주문번호는 등록될 때까지 보관
+    pub fn apply(&self, exec: &MyExec) {
+        if let Some(body) = exec.body() {
+            let mut inner = self.inner.lock().unwrap();
+            if let Some(journal) = &inner.journal {
+                let entry = JournalEntry::Exec {
+                    time: journal::now(),
+                    exec: body.into(),
+                };
+                if let Err(e) = journal.record(&entry) {
+                    warn!("Failed to journal MyExec: {}", e);
+                }
+            }
+            inner.apply(body.clone());
+        }
+    }
+
+    /// 기록된 체결통보 재생(journal에 다시 기록하지 않음)
+    pub(crate) fn apply_body(&self, body: my_exec::Body) {
+        self.inner.lock().unwrap().apply(body);
+    }
+
+    /// 주식일별주문체결조회 결과로 추적 중인 주문의 체결수량, 평균단가, 상태를 보정
+    /// 정정/취소 주문은 원주문번호로 묶고, 가장 최근 주문번호의 잔여수량을 기준으로 판단
+    pub(crate) fn reconcile(&self, rows: &[Output::InquireDailyCcld]) {
+        let num = |s: &str| s.trim().parse::<u64>().unwrap_or(0);
+        let mut inner = self.inner.lock().unwrap();
+        let mut groups = HashMap::<u64, Vec<&Output::InquireDailyCcld>>::new();
+        for row in rows {
+            let odno = num(row.odno());
+            let key = inner
+                .resolve(odno)
+                .or_else(|| inner.resolve(num(row.orgn_odno())));
+            if let Some(key) = key {
+                if odno != key {
+                    inner.aliases.insert(odno, key);
+                }
+                groups.entry(key).or_default().push(row);
+            }
+        }
+        for (key, rows) in groups {
+            let entry = inner.orders.get_mut(&key).unwrap();
+            let order = &mut entry.order;
+            let filled = rows.iter().map(|r| num(r.tot_ccld_qty())).sum::<u64>();
+            let amount = rows.iter().map(|r| num(r.tot_ccld_amt())).sum::<u64>();
+            let current = rows.iter().max_by_key(|r| num(r.odno())).unwrap();
+            let remaining = num(current.rmn_qty());
+            order.current_order_no = num(current.odno());
+            order.filled_qty = filled;
+            order.avg_price = if filled > 0 {
+                amount as f64 / filled as f64
+            } else {
+                0.0
+            };
+            if remaining > 0 {
+                order.order_qty = filled + remaining;
+                if num(current.ord_unpr()) > 0 {
+                    order.order_price = num(current.ord_unpr()) as u32;
+                }
+                order.state = if filled > 0 {
+                    OrderState::PartiallyFilled
+                } else {
+                    OrderState::Accepted
+                };
+            } else if rows.iter().any(|r| r.cncl_yn() == "Y") {
+                order.state = OrderState::Cancelled;
+            } else if filled == 0 && num(current.rjct_qty()) > 0 {
+                order.state = OrderState::Rejected;
+            } else if filled > 0 {
+                order.order_qty = filled;
+                order.state = OrderState::Filled;
+            }
+            entry.tx.send_replace(order.clone());
+        }
+    }
+
+    /// subscribe_my_exec의 수신 채널을 넘겨 백그라운드에서 반영
+    pub fn spawn(
+        &self,
+        mut rx: tokio::sync::mpsc::UnboundedReceiver<MyExec>,
+    ) -> tokio::task::JoinHandle<()> {
+        let tracker = self.clone();
+        tokio::spawn(async move {
+            while let Some(exec) = rx.recv().await {
+                tracker.apply(&exec);
+            }
+        })
+    }
+
+    /// 주문 현재 상태(정정/취소 주문번호로도 조회 가능)
+    pub fn get(&self, order_no: u64) -> Option<TrackedOrder> {
+        let inner = self.inner.lock().unwrap();
+        let key = inner.resolve(order_no)?;
+        inner.orders.get(&key).map(|entry| entry.order.clone())
+    }
+
+    /// 추적 중인 전체 주문
+    pub fn orders(&self) -> Vec<TrackedOrder> {
+        self.inner
+            .lock()
+            .unwrap()
+            .orders
+            .values()
+            .map(|entry| entry.order.clone())
+            .collect()
+    }
+
+    /// 주문 상태 변경 구독
+    pub fn subscribe(&self, order_no: u64) -> Option<watch::Receiver<TrackedOrder>> {
+        let inner = self.inner.lock().unwrap();
+        let key = inner.resolve(order_no)?;
+        inner.orders.get(&key).map(|entry| entry.tx.subscribe())
+    }
+
+    /// 주문이 종료 상태(Filled/Cancelled/Rejected)가 될 때까지 대기
+    pub async fn wait(&self, order_no: u64) -> Result<TrackedOrder, Error> {
+        let mut rx = self
+            .subscribe(order_no)
+            .ok_or_else(|| Error::InvalidParameter("order_no", order_no.to_string()))?;
+        loop {
+            {
+                let order = rx.borrow_and_update();
+                if order.state.is_terminal() {
+                    return Ok(order.clone());
+                }
+            }
+            if rx.changed().await.is_err() {
+                return Ok(rx.borrow().clone());
+            }
+        }
+    }
+}
+
+/// 오늘 날짜(YYYYMMDD, 한국시간)
+pub(crate) fn trading_date() -> String {
+    chrono::Utc::now()
+        .with_timezone(&chrono_tz::Asia::Seoul)
+        .format("%Y%m%d")
+        .to_string()
+}
+
+fn parse_order_no(response: &Body::Order) -> Result<u64, Error> {
+    match response.output() {
+        Some(output) if response.rt_cd() == "0" => Ok(output.odno().parse()?),
+        _ => Err(Error::BrokenProtocol(
+            "Order",
+            format!("{}: {}", response.msg_cd(), response.msg1()),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stock::journal::ExecRecord;
+    use crate::stock::testing::{apply, fill, notice, register, response, PDNO};
+    use crate::types::Quantity;
+
+    fn state(tracker: &OrderTracker, order_no: u64) -> OrderState {
+        tracker.get(order_no).unwrap().state()
+    }
+
+    #[test]
+    fn accept_and_fill() {
+        let tracker = OrderTracker::new();
+        register(&tracker, 1, Direction::Bid, 10);
+        assert_eq!(state(&tracker, 1), OrderState::New);
+        apply(&tracker, notice(1));
+        assert_eq!(state(&tracker, 1), OrderState::Accepted);
+        apply(&tracker, fill(1, 4, 1_000));
+        assert_eq!(state(&tracker, 1), OrderState::PartiallyFilled);
+        apply(&tracker, fill(1, 6, 1_010));
+        let order = tracker.get(1).unwrap();
+        assert_eq!(order.state(), OrderState::Filled);
+        assert_eq!(order.filled_qty(), 10);
+        assert!((order.avg_price() - 1_006.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn order_ref_keeps_class_and_exchange() {
+        let tracker = OrderTracker::new();
+        let request = OrderRequest::new(
+            Direction::Ask,
+            PDNO,
+            OrderClass::Market,
+            Quantity::from(5),
+            Price::from(0),
+            Some(Exchange::Nxt),
+        );
+        tracker.register(&request, &response(1)).unwrap();
+        let order = tracker.get(1).unwrap().order_ref();
+        assert_eq!(order.pdno().as_deref(), Some(PDNO));
+        assert_eq!(order.direction(), &Some(Direction::Ask));
+        assert_eq!(order.order_class(), &Some(OrderClass::Market));
+        assert_eq!(order.exchange(), &Some(Exchange::Nxt));
+    }
+
+    #[test]
+    fn accept_class() {
+        let tracker = OrderTracker::new();
+        register(&tracker, 1, Direction::Bid, 10);
+        register(&tracker, 2, Direction::Bid, 10);
+        apply(
+            &tracker,
+            ExecRecord {
+                accept_class: AcceptClass::Confirmed,
+                ..notice(1)
+            },
+        );
+        assert_eq!(state(&tracker, 1), OrderState::Accepted);
+        apply(
+            &tracker,
+            ExecRecord {
+                accept_class: AcceptClass::AutoCancelled,
+                ..notice(2)
+            },
+        );
+        assert_eq!(state(&tracker, 2), OrderState::Cancelled);
+    }
+
+    #[test]
+    fn refused() {
+        let tracker = OrderTracker::new();
+        register(&tracker, 1, Direction::Bid, 10);
+        apply(
+            &tracker,
+            ExecRecord {
+                is_refused: true,
+                ..notice(1)
+            },
+        );
+        assert_eq!(state(&tracker, 1), OrderState::Rejected);
+    }
+
+    #[test]
+    fn notices_before_register() {
+        let tracker = OrderTracker::new();
+        apply(&tracker, notice(1));
+        apply(&tracker, fill(1, 3, 1_000));
+        assert!(tracker.get(1).is_none());
+        register(&tracker, 1, Direction::Bid, 10);
+        let order = tracker.get(1).unwrap();
+        assert_eq!(order.state(), OrderState::PartiallyFilled);
+        assert_eq!(order.filled_qty(), 3);
+    }
+
+    #[test]
+    fn pending_is_bounded() {
+        let tracker = OrderTracker::new();
+        for order_no in 1..=(MAX_PENDING as u64 + 10) {
+            apply(&tracker, notice(order_no));
+        }
+        let inner = tracker.inner.lock().unwrap();
+        assert_eq!(inner.pending.len(), MAX_PENDING);
+        assert!(!inner.pending.contains_key(&1));
+    }
+
+    #[test]
+    fn partial_and_full_cancel() {
+        let tracker = OrderTracker::new();
+        register(&tracker, 1, Direction::Bid, 10);
+        apply(&tracker, notice(1));
+        apply(&tracker, fill(1, 2, 1_000));
+        // 취소 응답만으로는 상태를 바꾸지 않음
+        tracker
+            .apply_correction(1, CorrectionClass::Cancel, Price::from(0), &response(2))
+            .unwrap();
+        assert_eq!(state(&tracker, 1), OrderState::PartiallyFilled);
+        apply(
+            &tracker,
+            ExecRecord {
+                origin_order_no: 1,
+                correction_class: CorrectionClass::Cancel,
+                order_qty: 3,
+                ..notice(2)
+            },
+        );
+        let order = tracker.get(1).unwrap();
+        assert_eq!(order.state(), OrderState::PartiallyFilled);
+        assert_eq!(order.order_qty(), 7);
+        assert_eq!(order.remaining_qty(), 5);
+        apply(
+            &tracker,
+            ExecRecord {
+                origin_order_no: 1,
+                correction_class: CorrectionClass::Cancel,
+                order_qty: 5,
+                ..notice(3)
+            },
+        );
+        assert_eq!(state(&tracker, 1), OrderState::Cancelled);
+        assert_eq!(state(&tracker, 3), OrderState::Cancelled);
+    }
+
+    #[test]
+    fn fill_after_cancel() {
+        let tracker = OrderTracker::new();
+        register(&tracker, 1, Direction::Bid, 10);
+        apply(
+            &tracker,
+            ExecRecord {
+                origin_order_no: 1,
+                correction_class: CorrectionClass::Cancel,
+                order_qty: 10,
+                ..notice(2)
+            },
+        );
+        assert_eq!(state(&tracker, 1), OrderState::Cancelled);
+        apply(&tracker, fill(1, 4, 1_000));
+        let order = tracker.get(1).unwrap();
+        assert_eq!(order.state(), OrderState::Cancelled);
+        assert_eq!(order.filled_qty(), 4);
+    }
+
+    #[test]
+    fn correction() {
+        let tracker = OrderTracker::new();
+        register(&tracker, 1, Direction::Bid, 10);
+        apply(&tracker, notice(1));
+        apply(&tracker, fill(1, 2, 1_000));
+        tracker
+            .apply_correction(
+                1,
+                CorrectionClass::Correction,
+                Price::from(1_010),
+                &response(2),
+            )
+            .unwrap();
+        let order = tracker.get(2).unwrap();
+        assert_eq!(order.order_no(), 1);
+        assert_eq!(order.current_order_no(), 2);
+        assert_eq!(order.order_price(), 1_010);
+        let order_ref = order.order_ref();
+        assert_eq!(order_ref.odno(), "0000000002");
+        assert_eq!(order_ref.direction(), &Some(Direction::Bid));
+        assert_eq!(order_ref.order_class(), &Some(OrderClass::Limit));
+        apply(
+            &tracker,
+            ExecRecord {
+                origin_order_no: 1,
+                correction_class: CorrectionClass::Correction,
+                exec_price: 1_010,
+                order_qty: 8,
+                ..notice(2)
+            },
+        );
+        apply(&tracker, fill(2, 8, 1_010));
+        let order = tracker.get(1).unwrap();
+        assert_eq!(order.state(), OrderState::Filled);
+        assert_eq!(order.filled_qty(), 10);
+    }
+}
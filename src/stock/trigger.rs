@@ -0,0 +1,340 @@
+use crate::stock::order::Korea;
+use crate::types::request::stock::order::OrderRequest;
+use crate::types::response::stock::order::Body;
+use crate::types::stream::stock::{exec, Exec, StreamParser};
+use crate::types::{Direction, Exchange, OrderClass, Price, Quantity};
+use crate::Error;
+use getset::{CopyGetters, Getters};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+
+/// 발동 조건
+/// 매도 트리거는 보유 물량 보호 기준(손절: 가격 하락, 익절: 가격 상승),
+/// 매수 트리거는 그 반대 방향으로 판단
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TriggerCondition {
+    /// 손절: 매도는 stop_price 이하, 매수는 stop_price 이상
+    Stop { stop_price: u32 },
+    /// 익절: 매도는 limit_price 이상, 매수는 limit_price 이하
+    TakeProfit { limit_price: u32 },
+    /// 손절/익절 중 먼저 충족된 쪽으로 한 번만 발동
+    Oco {
+        stop_price: u32,
+        take_profit_price: u32,
+    },
+    /// 추적 손절: 매도는 고가 대비, 매수는 저가 대비 trail만큼 되돌리면 발동
+    Trailing {
+        trail: Trail,
+        /// 무장 이후 고가(매도) 또는 저가(매수)
+        #[serde(default)]
+        extreme: u32,
+    },
+}
+
+/// 추적 손절 폭
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trail {
+    /// 원
+    Amount(u32),
+    /// %
+    Percent(f64),
+}
+
+/// 조건 판단에 쓰는 가격
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceSource {
+    /// 현재가(체결가)
+    #[default]
+    Last,
+    /// 매도 트리거는 매수호가1, 매수 트리거는 매도호가1
+    Quote,
+}
+
+/// 무장된 조건부 주문
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+pub struct Trigger {
+    /// arm에서 부여
+    #[getset(get_copy = "pub")]
+    id: u64,
+    #[getset(get = "pub")]
+    pdno: String,
+    #[getset(get = "pub")]
+    direction: Direction,
+    /// 발동 시 주문구분
+    #[getset(get = "pub")]
+    order_class: OrderClass,
+    #[getset(get = "pub")]
+    qty: Quantity,
+    /// 발동 시 주문단가(시장가 등은 0)
+    #[getset(get_copy = "pub")]
+    price: Price,
+    #[getset(get = "pub")]
+    condition: TriggerCondition,
+    #[getset(get = "pub")]
+    source: PriceSource,
+    /// 발동 시 주문 거래소
+    #[getset(get = "pub")]
+    #[serde(default)]
+    exchange: Option<Exchange>,
+}
+
+impl Trigger {
+    /// 발동 시 제출할 주문과 조건
+    pub fn new(request: &OrderRequest, condition: TriggerCondition) -> Self {
+        Self {
+            id: 0,
+            pdno: request.pdno().clone(),
+            direction: request.direction().clone(),
+            order_class: request.order_class().clone(),
+            qty: request.qty().clone(),
+            price: *request.price(),
+            condition,
+            source: PriceSource::default(),
+            exchange: *request.exchange(),
+        }
+    }
+
+    pub fn with_source(mut self, source: PriceSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    fn request(&self) -> Result<OrderRequest, Error> {
+        let mut builder = OrderRequest::builder(self.direction.clone(), &self.pdno)
+            .order_class(self.order_class.clone())
+            .qty(self.qty.clone())
+            .price(self.price);
+        if let Some(exchange) = self.exchange {
+            builder = builder.exchange(exchange);
+        }
+        builder.build()
+    }
+
+    /// 가격 반영. 추적 손절 기준가가 바뀌었으면 .1이 true
+    fn update(&mut self, body: &exec::Body) -> (bool, bool) {
+        let price = match (&self.source, &self.direction) {
+            (PriceSource::Last, _) => body.current_price,
+            (PriceSource::Quote, Direction::Ask) => body.bid_price,
+            (PriceSource::Quote, Direction::Bid) => body.ask_price,
+        };
+        if price == 0 {
+            return (false, false);
+        }
+        let selling = matches!(self.direction, Direction::Ask);
+        let stop_hit = |stop: u32| {
+            if selling {
+                price <= stop
+            } else {
+                price >= stop
+            }
+        };
+        let take_hit = |take: u32| {
+            if selling {
+                price >= take
+            } else {
+                price <= take
+            }
+        };
+        match &mut self.condition {
+            TriggerCondition::Stop { stop_price } => (stop_hit(*stop_price), false),
+            TriggerCondition::TakeProfit { limit_price } => (take_hit(*limit_price), false),
+            TriggerCondition::Oco {
+                stop_price,
+                take_profit_price,
+            } => (stop_hit(*stop_price) || take_hit(*take_profit_price), false),
+            TriggerCondition::Trailing { trail, extreme } => {
+                let moved = *extreme == 0
+                    || (selling && price > *extreme)
+                    || (!selling && price < *extreme);
+                if moved {
+                    *extreme = price;
+                }
+                let width = match trail {
+                    Trail::Amount(amount) => *amount as f64,
+                    Trail::Percent(percent) => *extreme as f64 * *percent / 100.0,
+                };
+                let fired = if selling {
+                    (price as f64) <= *extreme as f64 - width
+                } else {
+                    (price as f64) >= *extreme as f64 + width
+                };
+                (fired, moved)
+            }
+        }
+    }
+}
+
+/// 발동 결과 알림
+#[derive(Debug)]
+pub enum TriggerEvent {
+    /// 발동하여 주문 접수
+    Fired {
+        trigger: Trigger,
+        response: Body::Order,
+    },
+    /// 발동했으나 주문 실패(거부 포함). 트리거는 해제된 상태
+    Failed { trigger: Trigger, error: String },
+}
+
+struct State {
+    triggers: BTreeMap<u64, Trigger>,
+    next_id: u64,
+}
+
+/// 실시간 체결가로 손절/익절/OCO/추적 손절 조건을 감시해 주문을 제출
+/// 무장된 트리거는 path가 있으면 JSON 파일로 보관하여 재시작 후 복원
+#[derive(Clone)]
+pub struct TriggerEngine {
+    korea: Korea,
+    path: Option<PathBuf>,
+    state: Arc<Mutex<State>>,
+    tx: mpsc::UnboundedSender<TriggerEvent>,
+}
+
+impl TriggerEngine {
+    pub fn new<P: AsRef<Path>>(
+        korea: &Korea,
+        path: Option<P>,
+    ) -> Result<(Self, mpsc::UnboundedReceiver<TriggerEvent>), Error> {
+        let path = path.map(|path| path.as_ref().to_path_buf());
+        let triggers = match &path {
+            Some(path) if path.exists() => {
+                serde_json::from_str::<Vec<Trigger>>(&std::fs::read_to_string(path)?)?
+            }
+            _ => vec![],
+        };
+        let next_id = triggers.iter().map(|t| t.id).max().unwrap_or(0) + 1;
+        let (tx, rx) = mpsc::unbounded_channel();
+        Ok((
+            Self {
+                korea: korea.clone(),
+                path,
+                state: Arc::new(Mutex::new(State {
+                    triggers: triggers.into_iter().map(|t| (t.id, t)).collect(),
+                    next_id,
+                })),
+                tx,
+            },
+            rx,
+        ))
+    }
+
+    /// 트리거 무장. 발동 주문을 미리 검증하고 id를 반환
+    pub fn arm(&self, mut trigger: Trigger) -> Result<u64, Error> {
+        trigger.request()?;
+        let mut state = self.state.lock().unwrap();
+        trigger.id = state.next_id;
+        state.next_id += 1;
+        let id = trigger.id;
+        state.triggers.insert(id, trigger);
+        self.save(&state)?;
+        Ok(id)
+    }
+
+    /// 트리거 해제
+    pub fn disarm(&self, id: u64) -> Result<Option<Trigger>, Error> {
+        let mut state = self.state.lock().unwrap();
+        let trigger = state.triggers.remove(&id);
+        if trigger.is_some() {
+            self.save(&state)?;
+        }
+        Ok(trigger)
+    }
+
+    /// 무장된 트리거
+    pub fn triggers(&self) -> Vec<Trigger> {
+        self.state
+            .lock()
+            .unwrap()
+            .triggers
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// 실시간 체결가 반영. 조건이 충족된 트리거는 해제 후 주문 제출
+    pub async fn on_exec(&self, exec: &Exec) {
+        let body = match exec.body() {
+            Some(body) => body,
+            None => return,
+        };
+        let fired = {
+            let mut state = self.state.lock().unwrap();
+            let mut fired = vec![];
+            let mut changed = false;
+            for trigger in state.triggers.values_mut() {
+                if trigger.pdno != body.shortcode {
+                    continue;
+                }
+                let (hit, moved) = trigger.update(body);
+                changed |= moved;
+                if hit {
+                    fired.push(trigger.id);
+                }
+            }
+            let fired = fired
+                .into_iter()
+                .filter_map(|id| state.triggers.remove(&id))
+                .collect::<Vec<_>>();
+            if changed || !fired.is_empty() {
+                if let Err(e) = self.save(&state) {
+                    warn!("Failed to save triggers: {}", e);
+                }
+            }
+            fired
+        };
+        for trigger in fired {
+            info!("Trigger {} fired for {}", trigger.id, trigger.pdno);
+            let result = match trigger.request() {
+                Ok(request) => self.korea.order(request).await,
+                Err(e) => Err(e),
+            };
+            let event = match result {
+                Ok(response) if response.rt_cd() == "0" => {
+                    TriggerEvent::Fired { trigger, response }
+                }
+                Ok(response) => TriggerEvent::Failed {
+                    trigger,
+                    error: format!("{}: {}", response.msg_cd(), response.msg1()),
+                },
+                Err(e) => TriggerEvent::Failed {
+                    trigger,
+                    error: e.to_string(),
+                },
+            };
+            if let TriggerEvent::Failed { trigger, error } = &event {
+                warn!("Trigger {} failed: {}", trigger.id, error);
+            }
+            let _ = self.tx.send(event);
+        }
+    }
+
+    /// subscribe_market(RealtimeExec)의 수신 채널을 넘겨 백그라운드에서 감시
+    pub fn spawn(&self, mut rx: mpsc::UnboundedReceiver<Exec>) -> tokio::task::JoinHandle<()> {
+        let engine = self.clone();
+        tokio::spawn(async move {
+            while let Some(exec) = rx.recv().await {
+                engine.on_exec(&exec).await;
+            }
+        })
+    }
+
+    /// 임시 파일에 쓴 뒤 교체
+    fn save(&self, state: &State) -> Result<(), Error> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let json = serde_json::to_string(&state.triggers.values().collect::<Vec<_>>())?;
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, json)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
@@ -2,7 +2,9 @@ pub mod config;
 mod crypto;
 pub mod request;
 pub mod response;
+pub mod session;
 pub mod stream;
+pub mod tick;
 mod time;
 
 use crate::Error;
@@ -59,7 +61,7 @@ pub struct Account {
     pub acnt_prdt_cd: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Default, serde_with::DeserializeFromStr, SerializeDisplay)]
 #[repr(i32)]
 pub enum OrderClass {
     #[default]
@@ -149,8 +151,40 @@ impl From<&str> for OrderClass {
         }
     }
 }
+impl OrderClass {
+    /// 주문단가를 0으로 보내야 하는 주문구분(시장가, 최유리, 최우선, 장전/장후 시간외 등)
+    pub fn is_priceless(&self) -> bool {
+        matches!(
+            self,
+            Self::Market
+                | Self::Best
+                | Self::First
+                | Self::PreMarket
+                | Self::PostMarket
+                | Self::IOCMarket
+                | Self::FOKMarket
+                | Self::IOCBest
+                | Self::FOKBest
+        )
+    }
+}
+impl std::fmt::Display for OrderClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&Into::<String>::into(self.clone()))
+    }
+}
+impl std::str::FromStr for OrderClass {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "00" | "01" | "02" | "03" | "04" | "05" | "06" | "07" | "08" | "09" | "10" | "11"
+            | "12" | "13" | "14" | "15" | "16" | "51" => Ok(s.into()),
+            _ => Err(Error::BrokenProtocol("OrderClass", s.to_string())),
+        }
+    }
+}
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde_with::DeserializeFromStr, SerializeDisplay)]
 #[repr(i32)]
 pub enum CorrectionClass {
     None = 0,
@@ -170,15 +204,64 @@ impl Into<String> for CorrectionClass {
 impl From<&str> for CorrectionClass {
     fn from(s: &str) -> CorrectionClass {
         match s {
-            "0" => CorrectionClass::None,
-            "01" => CorrectionClass::Correction,
-            "02" => CorrectionClass::Cancel,
+            "0" | "00" => CorrectionClass::None,
+            "1" | "01" => CorrectionClass::Correction,
+            "2" | "02" => CorrectionClass::Cancel,
             _ => todo!(),
         }
     }
 }
+impl std::fmt::Display for CorrectionClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&Into::<String>::into(self.clone()))
+    }
+}
+impl std::str::FromStr for CorrectionClass {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "0" | "01" | "02" => Ok(s.into()),
+            _ => Err(Error::BrokenProtocol("CorrectionClass", s.to_string())),
+        }
+    }
+}
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// 체결통보 접수여부(ACPT_YN)
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, serde_with::DeserializeFromStr, SerializeDisplay,
+)]
+#[repr(i32)]
+pub enum AcceptClass {
+    /// 접수(1)
+    #[default]
+    Received = 1,
+    /// 확인(2)
+    Confirmed = 2,
+    /// IOC/FOK 잔량 자동취소(3)
+    AutoCancelled = 3,
+}
+impl std::fmt::Display for AcceptClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Received => "1",
+            Self::Confirmed => "2",
+            Self::AutoCancelled => "3",
+        })
+    }
+}
+impl std::str::FromStr for AcceptClass {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "1" => Ok(Self::Received),
+            "2" => Ok(Self::Confirmed),
+            "3" => Ok(Self::AutoCancelled),
+            _ => Err(Error::BrokenProtocol("AcceptClass", s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde_with::DeserializeFromStr, SerializeDisplay)]
 #[repr(i32)]
 pub enum Direction {
     /// buy
@@ -196,8 +279,27 @@ impl From<&str> for Direction {
         }
     }
 }
+/// 매도매수구분코드(01: 매도, 02: 매수)
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Ask => "01",
+            Self::Bid => "02",
+        })
+    }
+}
+impl std::str::FromStr for Direction {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "01" => Ok(Self::Ask),
+            "02" => Ok(Self::Bid),
+            _ => Err(Error::BrokenProtocol("Direction", s.to_string())),
+        }
+    }
+}
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde_with::DeserializeFromStr, SerializeDisplay)]
 pub struct Quantity {
     inner: u32,
 }
@@ -205,6 +307,9 @@ impl Quantity {
     pub fn from(quantity: u32) -> Self {
         Self { inner: quantity }
     }
+    pub fn inner(&self) -> u32 {
+        self.inner
+    }
 }
 impl Into<String> for Quantity {
     fn into(self) -> String {
@@ -218,8 +323,21 @@ impl From<&str> for Quantity {
         }
     }
 }
+impl std::fmt::Display for Quantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+impl std::str::FromStr for Quantity {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(Self {
+            inner: s.trim().parse()?,
+        })
+    }
+}
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, serde_with::DeserializeFromStr, SerializeDisplay)]
 pub struct Price {
     inner: u32,
 }
@@ -227,6 +345,9 @@ impl Price {
     pub fn from(price: u32) -> Self {
         Self { inner: price }
     }
+    pub fn inner(&self) -> u32 {
+        self.inner
+    }
 }
 impl Into<String> for Price {
     fn into(self) -> String {
@@ -240,6 +361,19 @@ impl From<&str> for Price {
         }
     }
 }
+impl std::fmt::Display for Price {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+impl std::str::FromStr for Price {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(Self {
+            inner: s.trim().parse()?,
+        })
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq)]
 pub enum TrId {
@@ -257,9 +391,57 @@ pub enum TrId {
     RealStockCorrection,
     #[serde(rename = "VTTC0803U")]
     VirtualStockCorrection,
+    // Inquiry
+    #[serde(rename = "TTTC8434R")]
+    RealInquireBalance,
+    #[serde(rename = "VTTC8434R")]
+    VirtualInquireBalance,
+    #[serde(rename = "TTTC8908R")]
+    RealInquirePsblOrder,
+    #[serde(rename = "VTTC8908R")]
+    VirtualInquirePsblOrder,
+    #[serde(rename = "TTTC8408R")]
+    InquirePsblSell,
+    #[serde(rename = "TTTC8036R")]
+    InquirePsblRvsecncl,
+    #[serde(rename = "TTTC8001R")]
+    RealInquireDailyCcld,
+    #[serde(rename = "VTTC8001R")]
+    VirtualInquireDailyCcld,
+    #[serde(rename = "TTTC8708R")]
+    InquirePeriodProfit,
+    #[serde(rename = "TTTC8715R")]
+    InquirePeriodTradeProfit,
+    #[serde(rename = "TTTC8494R")]
+    InquireBalanceRlzPl,
+    #[serde(rename = "CTRP6548R")]
+    InquireAccountBalance,
+    // Reservation
+    #[serde(rename = "CTSC0008U")]
+    ReserveOrder,
+    #[serde(rename = "CTSC0013U")]
+    ReserveOrderCorrection,
+    #[serde(rename = "CTSC0009U")]
+    ReserveOrderCancel,
+    #[serde(rename = "CTSC0004R")]
+    InquireReserveOrder,
     // Quote
+    #[serde(rename = "FHKST01010100")]
+    CurrentPrice,
     #[serde(rename = "FHKST01010400")]
     DailyPrice,
+    #[serde(rename = "FHKST01010200")]
+    AskingPriceExpCcn,
+    #[serde(rename = "FHKST01010300")]
+    Ccnl,
+    #[serde(rename = "FHPST01060000")]
+    TimeItemConclusion,
+    #[serde(rename = "FHKST03010200")]
+    TimeItemChartPrice,
+    #[serde(rename = "FHKST03010230")]
+    TimeDailyChartPrice,
+    #[serde(rename = "FHKST03010100")]
+    DailyItemChartPrice,
     #[serde(rename = "FHPST01710000")]
     VolumeRank,
     #[serde(rename = "HHKCM113004C7")]
@@ -292,8 +474,33 @@ impl Into<String> for TrId {
             // Correction
             TrId::RealStockCorrection => "TTTC0803U",
             TrId::VirtualStockCorrection => "VTTC0803U",
+            // Inquiry
+            TrId::RealInquireBalance => "TTTC8434R",
+            TrId::VirtualInquireBalance => "VTTC8434R",
+            TrId::RealInquirePsblOrder => "TTTC8908R",
+            TrId::VirtualInquirePsblOrder => "VTTC8908R",
+            TrId::InquirePsblSell => "TTTC8408R",
+            TrId::InquirePsblRvsecncl => "TTTC8036R",
+            TrId::RealInquireDailyCcld => "TTTC8001R",
+            TrId::VirtualInquireDailyCcld => "VTTC8001R",
+            TrId::InquirePeriodProfit => "TTTC8708R",
+            TrId::InquirePeriodTradeProfit => "TTTC8715R",
+            TrId::InquireBalanceRlzPl => "TTTC8494R",
+            TrId::InquireAccountBalance => "CTRP6548R",
+            // Reservation
+            TrId::ReserveOrder => "CTSC0008U",
+            TrId::ReserveOrderCorrection => "CTSC0013U",
+            TrId::ReserveOrderCancel => "CTSC0009U",
+            TrId::InquireReserveOrder => "CTSC0004R",
             // Quote
+            TrId::CurrentPrice => "FHKST01010100",
             TrId::DailyPrice => "FHKST01010400",
+            TrId::AskingPriceExpCcn => "FHKST01010200",
+            TrId::Ccnl => "FHKST01010300",
+            TrId::TimeItemConclusion => "FHPST01060000",
+            TrId::TimeItemChartPrice => "FHKST03010200",
+            TrId::TimeDailyChartPrice => "FHKST03010230",
+            TrId::DailyItemChartPrice => "FHKST03010100",
             TrId::VolumeRank => "FHPST01710000",
             TrId::InstockGrouplist => "HHKCM113004C7",
             TrId::InstockGroupItem => "HHKCM113004C6",
@@ -321,8 +528,33 @@ impl From<&str> for TrId {
             // Correction
             "TTTC0803U" => TrId::RealStockCorrection,
             "VTTC0803U" => TrId::VirtualStockCorrection,
+            // Inquiry
+            "TTTC8434R" => TrId::RealInquireBalance,
+            "VTTC8434R" => TrId::VirtualInquireBalance,
+            "TTTC8908R" => TrId::RealInquirePsblOrder,
+            "VTTC8908R" => TrId::VirtualInquirePsblOrder,
+            "TTTC8408R" => TrId::InquirePsblSell,
+            "TTTC8036R" => TrId::InquirePsblRvsecncl,
+            "TTTC8001R" => TrId::RealInquireDailyCcld,
+            "VTTC8001R" => TrId::VirtualInquireDailyCcld,
+            "TTTC8708R" => TrId::InquirePeriodProfit,
+            "TTTC8715R" => TrId::InquirePeriodTradeProfit,
+            "TTTC8494R" => TrId::InquireBalanceRlzPl,
+            "CTRP6548R" => TrId::InquireAccountBalance,
+            // Reservation
+            "CTSC0008U" => TrId::ReserveOrder,
+            "CTSC0013U" => TrId::ReserveOrderCorrection,
+            "CTSC0009U" => TrId::ReserveOrderCancel,
+            "CTSC0004R" => TrId::InquireReserveOrder,
             // Quote
+            "FHKST01010100" => TrId::CurrentPrice,
             "FHKST01010400" => TrId::DailyPrice,
+            "FHKST01010200" => TrId::AskingPriceExpCcn,
+            "FHKST01010300" => TrId::Ccnl,
+            "FHPST01060000" => TrId::TimeItemConclusion,
+            "FHKST03010200" => TrId::TimeItemChartPrice,
+            "FHKST03010230" => TrId::TimeDailyChartPrice,
+            "FHKST03010100" => TrId::DailyItemChartPrice,
             "FHPST01710000" => TrId::VolumeRank,
             "HHKCM113004C7" => TrId::InstockGrouplist,
             "HHKCM113004C6" => TrId::InstockGroupItem,
@@ -339,6 +571,138 @@ impl From<&str> for TrId {
     }
 }
 
+/// 잔고조회 조회구분
+#[derive(Clone, Debug, Deserialize, SerializeDisplay)]
+#[repr(i32)]
+pub enum BalanceInquiryClass {
+    /// 대출일별(01)
+    #[serde(rename = "01")]
+    ByLoanDate = 1,
+    /// 종목별(02)
+    #[serde(rename = "02")]
+    ByStock = 2,
+}
+impl std::fmt::Display for BalanceInquiryClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::ByLoanDate => "01",
+            Self::ByStock => "02",
+        })
+    }
+}
+
+/// 기간별손익 정렬구분
+#[derive(Clone, Debug, Deserialize, SerializeDisplay)]
+#[repr(i32)]
+pub enum SortClass {
+    /// 최근 순(00)
+    #[serde(rename = "00")]
+    Recent = 0,
+    /// 과거 순(01)
+    #[serde(rename = "01")]
+    Past = 1,
+}
+impl std::fmt::Display for SortClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Recent => "00",
+            Self::Past => "01",
+        })
+    }
+}
+
+/// 투자계좌자산현황조회 자산구분(응답 output1의 순서)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(usize)]
+pub enum AccountAssetClass {
+    /// 주식
+    Stock = 0,
+    /// 펀드/MMW
+    Fund = 1,
+    /// 채권
+    Bond = 2,
+    /// ELS/DLS
+    ElsDls = 3,
+    /// WRAP
+    Wrap = 4,
+    /// 신탁/퇴직연금/외화신탁
+    Trust = 5,
+    /// RP/발행어음
+    Rp = 6,
+    /// 해외주식
+    ForeignStock = 7,
+    /// 해외채권
+    ForeignBond = 8,
+    /// 금현물
+    Gold = 9,
+    /// CD/CP
+    CdCp = 10,
+    /// 단기사채
+    ShortTermBond = 11,
+    /// 타사상품
+    OtherCompanyProduct = 12,
+    /// 외화단기사채
+    ForeignShortTermBond = 13,
+    /// 외화 ELS/DLS
+    ForeignElsDls = 14,
+    /// 외화
+    ForeignCurrency = 15,
+    /// 예수금+CMA
+    Deposit = 16,
+    /// 청약자예수금
+    SubscriptionDeposit = 17,
+    /// 합계
+    Total = 18,
+}
+
+/// 예약주문조회 처리구분코드
+#[derive(Clone, Debug, Deserialize, SerializeDisplay)]
+#[repr(i32)]
+pub enum ReservationProcessClass {
+    /// 전체(0)
+    #[serde(rename = "0")]
+    All = 0,
+    /// 처리내역(1)
+    #[serde(rename = "1")]
+    Processed = 1,
+    /// 미처리내역(2)
+    #[serde(rename = "2")]
+    Unprocessed = 2,
+}
+impl std::fmt::Display for ReservationProcessClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::All => "0",
+            Self::Processed => "1",
+            Self::Unprocessed => "2",
+        })
+    }
+}
+
+/// 일별주문체결조회 체결구분
+#[derive(Clone, Debug, Deserialize, SerializeDisplay)]
+#[repr(i32)]
+pub enum ExecutionClass {
+    /// 전체(00)
+    #[serde(rename = "00")]
+    All = 0,
+    /// 체결(01)
+    #[serde(rename = "01")]
+    Executed = 1,
+    /// 미체결(02)
+    #[serde(rename = "02")]
+    Unexecuted = 2,
+}
+impl std::fmt::Display for ExecutionClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::All => "00",
+            Self::Executed => "01",
+            Self::Unexecuted => "02",
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CustomerType {
     #[serde(rename = "B")]
@@ -380,7 +744,7 @@ impl From<&str> for ExecClass {
 }
 
 /// 대비구분
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize)]
 #[repr(i32)]
 pub enum VsPriceSign {
     /// 상한(1)
@@ -390,6 +754,7 @@ pub enum VsPriceSign {
     #[serde(rename = "2")]
     Increase = 2,
     /// 보합(3)
+    #[default]
     #[serde(rename = "3")]
     Steady = 3,
     /// 하락(4)
@@ -544,19 +909,72 @@ pub enum What {
 #[derive(Clone, Debug, Deserialize, SerializeDisplay)]
 #[repr(i32)]
 pub enum MarketCode {
+    /// 주식(KRX)
     #[serde(rename = "J")]
     Stock = 1,
     #[serde(rename = "ETF")]
     Etf = 2,
+    /// 넥스트레이드(NXT)
+    #[serde(rename = "NX")]
+    Nxt = 3,
+    /// 통합(KRX + NXT)
+    #[serde(rename = "UN")]
+    Unified = 4,
 }
 impl std::fmt::Display for MarketCode {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         f.write_str(match self {
             Self::Stock => "J",
             Self::Etf => "ETF",
+            Self::Nxt => "NX",
+            Self::Unified => "UN",
+        })
+    }
+}
+impl From<&Exchange> for MarketCode {
+    fn from(exchange: &Exchange) -> Self {
+        match exchange {
+            Exchange::Krx => Self::Stock,
+            Exchange::Nxt => Self::Nxt,
+            Exchange::Sor => Self::Unified,
+        }
+    }
+}
+
+/// 거래소ID구분코드
+#[derive(
+    Debug, Clone, Copy, PartialEq, Default, serde_with::DeserializeFromStr, SerializeDisplay,
+)]
+#[repr(i32)]
+pub enum Exchange {
+    /// 한국거래소
+    #[default]
+    Krx = 1,
+    /// 넥스트레이드
+    Nxt = 2,
+    /// 스마트주문라우팅(KRX/NXT 중 유리한 시장으로 전송)
+    Sor = 3,
+}
+impl std::fmt::Display for Exchange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Krx => "KRX",
+            Self::Nxt => "NXT",
+            Self::Sor => "SOR",
         })
     }
 }
+impl std::str::FromStr for Exchange {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "KRX" => Ok(Self::Krx),
+            "NXT" => Ok(Self::Nxt),
+            "SOR" => Ok(Self::Sor),
+            _ => Err(Error::BrokenProtocol("Exchange", s.to_string())),
+        }
+    }
+}
 
 #[derive(Clone, Debug, Deserialize, SerializeDisplay)]
 #[repr(i32)]
@@ -578,6 +996,34 @@ impl std::fmt::Display for PeriodCode {
     }
 }
 
+/// 기간별시세 기간 분류 코드
+#[derive(Clone, Debug, Deserialize, SerializeDisplay)]
+#[repr(i32)]
+pub enum ChartPeriod {
+    /// 일봉(D)
+    #[serde(rename = "D")]
+    Day = 1,
+    /// 주봉(W)
+    #[serde(rename = "W")]
+    Week = 2,
+    /// 월봉(M)
+    #[serde(rename = "M")]
+    Month = 3,
+    /// 년봉(Y)
+    #[serde(rename = "Y")]
+    Year = 4,
+}
+impl std::fmt::Display for ChartPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Day => "D",
+            Self::Week => "W",
+            Self::Month => "M",
+            Self::Year => "Y",
+        })
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, SerializeDisplay)]
 #[repr(i32)]
 pub enum ExCode {
@@ -1,6 +1,11 @@
-use crate::types::{CustomerType, TrId};
-use getset::{Getters, Setters};
-use serde::{Deserialize, Serialize};
+use crate::types::response::stock::order::Output;
+use crate::types::{
+    Account, BalanceInquiryClass, CustomerType, Direction, Exchange, ExecutionClass, OrderClass,
+    Price, Quantity, ReservationProcessClass, SortClass, Time, TrId,
+};
+use crate::Error;
+use getset::{CopyGetters, Getters, Setters};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Debug, Clone, PartialEq, Getters, Setters, Serialize, Deserialize)]
 pub struct Header {
@@ -30,9 +35,366 @@ impl Header {
     }
 }
 
+/// 검증된 주식주문(현금)
+/// OrderRequestBuilder::build를 통해서만 생성되며, into_body로 Body::Order를 만들어 제출
+#[derive(Debug, Clone, PartialEq, Getters)]
+pub struct OrderRequest {
+    /// 매수/매도
+    #[getset(get = "pub")]
+    direction: Direction,
+    /// 종목코드(6자리)
+    #[getset(get = "pub")]
+    pdno: String,
+    /// 주문구분
+    #[getset(get = "pub")]
+    order_class: OrderClass,
+    /// 주문수량(주문주식수)
+    #[getset(get = "pub")]
+    qty: Quantity,
+    /// 주문단가(시장가 등은 0)
+    #[getset(get = "pub")]
+    price: Price,
+    /// 거래소(None이면 Korea::set_exchange로 지정한 기본값)
+    #[getset(get = "pub")]
+    exchange: Option<Exchange>,
+}
+
+impl OrderRequest {
+    pub fn builder(direction: Direction, pdno: &str) -> OrderRequestBuilder {
+        OrderRequestBuilder::new(direction, pdno)
+    }
+
+    /// 검증 없이 생성(order_cash, 기록 복구 등 이미 주문 내용이 정해진 경우)
+    pub(crate) fn new(
+        direction: Direction,
+        pdno: &str,
+        order_class: OrderClass,
+        qty: Quantity,
+        price: Price,
+        exchange: Option<Exchange>,
+    ) -> Self {
+        Self {
+            direction,
+            pdno: pdno.to_string(),
+            order_class,
+            qty,
+            price,
+            exchange,
+        }
+    }
+
+    /// 거래소를 지정하지 않았으면 exchange 사용
+    pub(crate) fn or_exchange(mut self, exchange: Option<Exchange>) -> Self {
+        self.exchange = self.exchange.or(exchange);
+        self
+    }
+
+    pub fn into_body(self, account: &Account) -> Body::Order {
+        let mut body = Body::Order::new(
+            account.cano.clone(),
+            account.acnt_prdt_cd.clone(),
+            self.pdno,
+            self.order_class,
+            self.qty,
+            self.price,
+        );
+        body.set_excg_id_dvsn_cd(self.exchange);
+        body
+    }
+}
+
+/// OrderRequest 생성기
+/// 주문구분 기본값은 지정가(Limit), 수량/단가는 지정하지 않으면 build에서 에러
+#[derive(Debug, Clone)]
+pub struct OrderRequestBuilder {
+    direction: Direction,
+    pdno: String,
+    order_class: OrderClass,
+    qty: Option<Quantity>,
+    price: Option<Price>,
+    exchange: Option<Exchange>,
+}
+
+impl OrderRequestBuilder {
+    pub fn new(direction: Direction, pdno: &str) -> Self {
+        Self {
+            direction,
+            pdno: pdno.to_string(),
+            order_class: OrderClass::default(),
+            qty: None,
+            price: None,
+            exchange: None,
+        }
+    }
+
+    pub fn order_class(mut self, order_class: OrderClass) -> Self {
+        self.order_class = order_class;
+        self
+    }
+
+    pub fn qty(mut self, qty: Quantity) -> Self {
+        self.qty = Some(qty);
+        self
+    }
+
+    pub fn price(mut self, price: Price) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    /// 주문을 보낼 거래소(KRX, NXT, SOR)
+    pub fn exchange(mut self, exchange: Exchange) -> Self {
+        self.exchange = Some(exchange);
+        self
+    }
+
+    /// 시장가 주문(주문단가 0)
+    pub fn market(self) -> Self {
+        self.order_class(OrderClass::Market).price(Price::from(0))
+    }
+
+    /// 지정가 주문
+    pub fn limit(self, price: Price) -> Self {
+        self.order_class(OrderClass::Limit).price(price)
+    }
+
+    /// 종목코드, 수량, 주문구분별 단가 규칙을 검사
+    pub fn build(self) -> Result<OrderRequest, Error> {
+        if self.pdno.len() != 6
+            || !self
+                .pdno
+                .chars()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+        {
+            return Err(Error::InvalidParameter(
+                "pdno",
+                format!("{} is not a 6-character short code", self.pdno),
+            ));
+        }
+        let qty = match self.qty {
+            Some(qty) if qty.inner() > 0 => qty,
+            Some(_) => {
+                return Err(Error::InvalidParameter(
+                    "ord_qty",
+                    "quantity must be positive".to_string(),
+                ));
+            }
+            None => {
+                return Err(Error::InvalidParameter(
+                    "ord_qty",
+                    "quantity is not set".to_string(),
+                ));
+            }
+        };
+        let price = match &self.order_class {
+            OrderClass::MyStock
+            | OrderClass::MyStockSOption
+            | OrderClass::MyStockMoneyTrust
+            | OrderClass::MidMarketMassive => {
+                return Err(Error::InvalidParameter(
+                    "ord_dvsn",
+                    format!("{:?} is not supported for cash orders", self.order_class),
+                ));
+            }
+            class if class.is_priceless() => match self.price {
+                Some(price) if price.inner() != 0 => {
+                    return Err(Error::InvalidParameter(
+                        "ord_unpr",
+                        format!("{:?} order must have price 0", class),
+                    ));
+                }
+                _ => Price::from(0),
+            },
+            class => match self.price {
+                Some(price) if price.inner() > 0 => price,
+                _ => {
+                    return Err(Error::InvalidParameter(
+                        "ord_unpr",
+                        format!("{:?} order needs a positive price", class),
+                    ));
+                }
+            },
+        };
+        Ok(OrderRequest {
+            direction: self.direction,
+            pdno: self.pdno,
+            order_class: self.order_class,
+            qty,
+            price,
+            exchange: self.exchange,
+        })
+    }
+}
+
+/// 주문 핸들(정정/취소/조회 대상 주문)
+/// 주문번호와 영업점코드 외의 정보는 주문 제출(Korea::submit), 추적 주문, 정정취소가능주문조회로 만든 경우에만 채워짐
+#[derive(Debug, Clone, Getters)]
+pub struct OrderRef {
+    /// 한국거래소전송주문조직번호(주문시 한국투자증권 시스템에서 지정된 영업점코드)
+    #[getset(get = "pub")]
+    krx_fwdg_ord_orgno: String,
+    /// 주문번호
+    #[getset(get = "pub")]
+    odno: String,
+    /// 주문시각
+    #[getset(get = "pub")]
+    ord_tmd: Option<Time>,
+    /// 종목코드
+    #[getset(get = "pub")]
+    pdno: Option<String>,
+    /// 매수/매도
+    #[getset(get = "pub")]
+    direction: Option<Direction>,
+    /// 주문구분
+    #[getset(get = "pub")]
+    order_class: Option<OrderClass>,
+    /// 거래소
+    #[getset(get = "pub")]
+    exchange: Option<Exchange>,
+    /// 제출한 주문
+    #[getset(get = "pub")]
+    request: Option<OrderRequest>,
+}
+
+impl OrderRef {
+    pub fn new(krx_fwdg_ord_orgno: &str, odno: &str) -> Self {
+        Self {
+            krx_fwdg_ord_orgno: krx_fwdg_ord_orgno.to_string(),
+            odno: odno.to_string(),
+            ord_tmd: None,
+            pdno: None,
+            direction: None,
+            order_class: None,
+            exchange: None,
+            request: None,
+        }
+    }
+
+    /// 주문 응답으로 생성. 주문시각은 당일(한국시간) 기준으로 해석
+    /// 응답이 실패(rt_cd != 0)면 Error::OrderRejected
+    pub fn from_response(
+        request: OrderRequest,
+        response: &crate::types::response::stock::order::Body::Order,
+    ) -> Result<Self, Error> {
+        let (output, ord_tmd) = accepted(response)?;
+        Ok(Self {
+            ord_tmd,
+            pdno: Some(request.pdno.clone()),
+            direction: Some(request.direction.clone()),
+            order_class: Some(request.order_class.clone()),
+            exchange: request.exchange,
+            request: Some(request),
+            ..Self::from(output)
+        })
+    }
+
+    /// 정정 응답으로 생성(새 주문번호와 주문시각). 제출한 주문의 주문구분과 단가는 정정한 값으로 갱신
+    /// 응답이 실패(rt_cd != 0)면 Error::OrderRejected
+    pub fn from_correction(
+        order: &OrderRef,
+        order_class: OrderClass,
+        price: Price,
+        response: &crate::types::response::stock::order::Body::Order,
+    ) -> Result<Self, Error> {
+        let (output, ord_tmd) = accepted(response)?;
+        let request = order.request.clone().map(|request| OrderRequest {
+            order_class: order_class.clone(),
+            price,
+            ..request
+        });
+        Ok(Self {
+            ord_tmd,
+            pdno: order.pdno.clone(),
+            direction: order.direction.clone(),
+            order_class: Some(order_class),
+            exchange: order.exchange,
+            request,
+            ..Self::from(output)
+        })
+    }
+
+    /// 종목코드와 매수/매도 지정
+    pub fn with_order(mut self, pdno: &str, direction: Direction) -> Self {
+        self.pdno = Some(pdno.to_string());
+        self.direction = Some(direction);
+        self
+    }
+
+    /// 주문구분 지정
+    pub fn with_order_class(mut self, order_class: OrderClass) -> Self {
+        self.order_class = Some(order_class);
+        self
+    }
+
+    /// 거래소 지정
+    pub fn with_exchange(mut self, exchange: Exchange) -> Self {
+        self.exchange = Some(exchange);
+        self
+    }
+
+    /// 주문번호(숫자)
+    pub fn order_no(&self) -> Result<u64, Error> {
+        Ok(self.odno.trim().parse()?)
+    }
+}
+
+/// 성공 응답의 output과 주문시각(당일, 한국시간 기준)
+fn accepted(
+    response: &crate::types::response::stock::order::Body::Order,
+) -> Result<(&Output::Order, Option<Time>), Error> {
+    let output = match response.output() {
+        Some(output) if response.rt_cd() == "0" => output,
+        _ => {
+            return Err(Error::OrderRejected(
+                response.msg_cd().clone(),
+                response.msg1().clone(),
+            ));
+        }
+    };
+    let today = chrono::Utc::now()
+        .with_timezone(&chrono_tz::Asia::Seoul)
+        .format("%Y%m%d");
+    let ord_tmd = Time::parse(&format!("{}{}", today, output.ord_tmd()), "%Y%m%d%H%M%S").ok();
+    Ok((output, ord_tmd))
+}
+
+/// 영업점코드와 주문번호가 같으면 같은 주문
+impl PartialEq for OrderRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.krx_fwdg_ord_orgno == other.krx_fwdg_ord_orgno && self.odno == other.odno
+    }
+}
+
+impl From<&Output::Order> for OrderRef {
+    fn from(output: &Output::Order) -> Self {
+        Self::new(output.krx_fwdg_ord_orgno(), output.odno())
+    }
+}
+
+impl From<&Output::InquirePsblRvsecncl> for OrderRef {
+    fn from(output: &Output::InquirePsblRvsecncl) -> Self {
+        Self {
+            pdno: Some(output.pdno().clone()),
+            direction: output.sll_buy_dvsn_cd().parse().ok(),
+            order_class: Some(output.ord_dvsn_cd().clone()),
+            exchange: output.excg_id_dvsn_cd().parse().ok(),
+            ..Self::new(output.ord_gno_brno(), output.odno())
+        }
+    }
+}
+
+fn serialize_yn<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(if *value { "Y" } else { "N" })
+}
+
+fn deserialize_yn<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    Ok(String::deserialize(deserializer)? == "Y")
+}
+
 #[allow(non_snake_case)]
 pub mod Body {
-    use crate::types::{CorrectionClass, OrderClass, Price, Quantity};
+    use super::{deserialize_yn, serialize_yn};
+    use crate::types::{CorrectionClass, Direction, Exchange, OrderClass, Price, Quantity};
     use getset::{Getters, Setters};
     use serde::{Deserialize, Serialize};
 
@@ -57,6 +419,10 @@ pub mod Body {
         #[getset(get = "pub", set = "pub")]
         /// 주문단가(1주당 가격; 시장가는 0으로)
         ord_unpr: Price,
+        #[getset(get = "pub", set = "pub")]
+        /// 거래소ID구분코드(KRX, NXT, SOR; 없으면 KRX)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        excg_id_dvsn_cd: Option<Exchange>,
     }
 
     impl Order {
@@ -75,6 +441,7 @@ pub mod Body {
                 ord_dvsn,
                 ord_qty,
                 ord_unpr,
+                excg_id_dvsn_cd: None,
             }
         }
         pub fn get_json_string(self) -> String {
@@ -113,7 +480,12 @@ pub mod Body {
         ord_unpr: Price,
         /// 잔량전부주문여부([정정/취소] Y: 잔량전부, N: 잔량일부)
         #[getset(get = "pub", set = "pub")]
+        #[serde(serialize_with = "serialize_yn", deserialize_with = "deserialize_yn")]
         qty_all_ord_yn: bool,
+        /// 거래소ID구분코드(KRX, NXT, SOR; 없으면 KRX)
+        #[getset(get = "pub", set = "pub")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        excg_id_dvsn_cd: Option<Exchange>,
     }
     impl Correction {
         pub fn new(
@@ -137,6 +509,154 @@ pub mod Body {
                 ord_qty,
                 ord_unpr,
                 qty_all_ord_yn,
+                excg_id_dvsn_cd: None,
+            }
+        }
+        pub fn get_json_string(self) -> String {
+            serde_json::json!(self).to_string()
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Getters, Setters, Serialize, Deserialize)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct ReserveOrder {
+        /// 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+        #[getset(get = "pub", set = "pub")]
+        cano: String,
+        /// 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+        #[getset(get = "pub", set = "pub")]
+        acnt_prdt_cd: String,
+        /// 종목코드(6자리)
+        #[getset(get = "pub", set = "pub")]
+        pdno: String,
+        /// 주문수량(주문주식수)
+        #[getset(get = "pub", set = "pub")]
+        ord_qty: Quantity,
+        /// 주문단가(1주당 가격; 시장가/장전시간외는 0으로)
+        #[getset(get = "pub", set = "pub")]
+        ord_unpr: Price,
+        /// 매도매수구분코드(01: 매도, 02: 매수)
+        #[getset(get = "pub", set = "pub")]
+        sll_buy_dvsn_cd: Direction,
+        /// 주문구분코드(00: 지정가, 01: 시장가, 02: 조건부지정가, 05: 장전시간외)
+        #[getset(get = "pub", set = "pub")]
+        ord_dvsn_cd: OrderClass,
+        /// 주문대상잔고구분코드(10: 현금)
+        #[getset(get = "pub", set = "pub")]
+        ord_objt_cblc_dvsn_cd: String,
+        /// 대출일자
+        #[getset(get = "pub", set = "pub")]
+        loan_dt: String,
+        /// 예약주문종료일자(YYYYMMDD; 공란이면 일반예약주문, 입력하면 기간예약주문)
+        #[getset(get = "pub", set = "pub")]
+        rsvn_ord_end_dt: String,
+        /// 대여일자
+        #[getset(get = "pub", set = "pub")]
+        ldng_dt: String,
+    }
+    impl ReserveOrder {
+        /// 일반예약주문. 기간예약주문은 set_rsvn_ord_end_dt로 종료일자 지정
+        pub fn new(
+            cano: String,
+            acnt_prdt_cd: String,
+            pdno: String,
+            ord_qty: Quantity,
+            ord_unpr: Price,
+            sll_buy_dvsn_cd: Direction,
+            ord_dvsn_cd: OrderClass,
+        ) -> Self {
+            Self {
+                cano,
+                acnt_prdt_cd,
+                pdno,
+                ord_qty,
+                ord_unpr,
+                sll_buy_dvsn_cd,
+                ord_dvsn_cd,
+                ord_objt_cblc_dvsn_cd: "10".to_string(),
+                loan_dt: "".to_string(),
+                rsvn_ord_end_dt: "".to_string(),
+                ldng_dt: "".to_string(),
+            }
+        }
+        pub fn get_json_string(self) -> String {
+            serde_json::json!(self).to_string()
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Getters, Setters, Serialize, Deserialize)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct ReserveCorrection {
+        /// 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+        #[getset(get = "pub", set = "pub")]
+        cano: String,
+        /// 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+        #[getset(get = "pub", set = "pub")]
+        acnt_prdt_cd: String,
+        /// 종목코드(6자리)
+        #[getset(get = "pub", set = "pub")]
+        pdno: String,
+        /// 주문수량(주문주식수)
+        #[getset(get = "pub", set = "pub")]
+        ord_qty: Quantity,
+        /// 주문단가(1주당 가격; 시장가/장전시간외는 0으로)
+        #[getset(get = "pub", set = "pub")]
+        ord_unpr: Price,
+        /// 매도매수구분코드(01: 매도, 02: 매수)
+        #[getset(get = "pub", set = "pub")]
+        sll_buy_dvsn_cd: Direction,
+        /// 주문구분코드(00: 지정가, 01: 시장가, 02: 조건부지정가, 05: 장전시간외)
+        #[getset(get = "pub", set = "pub")]
+        ord_dvsn_cd: OrderClass,
+        /// 주문대상잔고구분코드(10: 현금)
+        #[getset(get = "pub", set = "pub")]
+        ord_objt_cblc_dvsn_cd: String,
+        /// 대출일자
+        #[getset(get = "pub", set = "pub")]
+        loan_dt: String,
+        /// 예약주문종료일자(YYYYMMDD)
+        #[getset(get = "pub", set = "pub")]
+        rsvn_ord_end_dt: String,
+        /// 연락전화번호
+        #[getset(get = "pub", set = "pub")]
+        ctal_tlno: String,
+        /// 예약주문순번(예약주문조회 output의 rsvn_ord_seq)
+        #[getset(get = "pub", set = "pub")]
+        rsvn_ord_seq: String,
+        /// 예약주문조직번호
+        #[getset(get = "pub", set = "pub")]
+        rsvn_ord_orgno: String,
+        /// 예약주문주문일자(YYYYMMDD)
+        #[getset(get = "pub", set = "pub")]
+        rsvn_ord_ord_dt: String,
+    }
+    impl ReserveCorrection {
+        /// 대상 예약주문(rsvn_ord_seq, rsvn_ord_orgno, rsvn_ord_ord_dt, rsvn_ord_end_dt)은
+        /// 예약주문조회 output의 값으로 setter를 통해 지정
+        pub fn new(
+            cano: String,
+            acnt_prdt_cd: String,
+            pdno: String,
+            ord_qty: Quantity,
+            ord_unpr: Price,
+            sll_buy_dvsn_cd: Direction,
+            ord_dvsn_cd: OrderClass,
+        ) -> Self {
+            Self {
+                cano,
+                acnt_prdt_cd,
+                pdno,
+                ord_qty,
+                ord_unpr,
+                sll_buy_dvsn_cd,
+                ord_dvsn_cd,
+                ord_objt_cblc_dvsn_cd: "10".to_string(),
+                loan_dt: "".to_string(),
+                rsvn_ord_end_dt: "".to_string(),
+                ctal_tlno: "".to_string(),
+                rsvn_ord_seq: "".to_string(),
+                rsvn_ord_orgno: "".to_string(),
+                rsvn_ord_ord_dt: "".to_string(),
             }
         }
         pub fn get_json_string(self) -> String {
@@ -144,3 +664,630 @@ pub mod Body {
         }
     }
 }
+
+/// 주식잔고조회[v1_국내주식-006]
+#[derive(Debug, Clone, Getters, CopyGetters, Setters, Serialize)]
+pub struct InquireBalanceParameter {
+    /// 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub", set = "pub")]
+    cano: String,
+    /// 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub", set = "pub")]
+    acnt_prdt_cd: String,
+    /// 시간외단일가여부(N: 기본값, Y: 시간외단일가)
+    #[getset(get_copy = "pub", set = "pub")]
+    afhr_flpr_yn: bool,
+    /// 오프라인여부(공란)
+    #[getset(get = "pub", set = "pub")]
+    ofl_yn: String,
+    /// 조회구분(01: 대출일별, 02: 종목별)
+    #[getset(get = "pub", set = "pub")]
+    inqr_dvsn: BalanceInquiryClass,
+    /// 단가구분(01)
+    #[getset(get = "pub", set = "pub")]
+    unpr_dvsn: String,
+    /// 펀드결제분포함여부(N: 포함하지 않음, Y: 포함)
+    #[getset(get_copy = "pub", set = "pub")]
+    fund_sttl_icld_yn: bool,
+    /// 융자금액자동상환여부(N)
+    #[getset(get_copy = "pub", set = "pub")]
+    fncg_amt_auto_rdpt_yn: bool,
+    /// 처리구분(00: 전일매매포함, 01: 전일매매미포함)
+    #[getset(get_copy = "pub", set = "pub")]
+    prcs_dvsn_include_yesterday: bool,
+    /// 연속조회검색조건100(최초 조회시 공란, 다음 조회부터 이전 조회 Output의 값)
+    #[getset(get = "pub", set = "pub")]
+    ctx_area_fk100: String,
+    /// 연속조회키100(최초 조회시 공란, 다음 조회부터 이전 조회 Output의 값)
+    #[getset(get = "pub", set = "pub")]
+    ctx_area_nk100: String,
+}
+
+impl InquireBalanceParameter {
+    /// 연속조회키는 공란(최초 조회). 다음 조회는 set_ctx_area_fk100, set_ctx_area_nk100으로 지정
+    pub fn new(
+        cano: String,
+        acnt_prdt_cd: String,
+        inqr_dvsn: BalanceInquiryClass,
+        afhr_flpr_yn: bool,
+        fund_sttl_icld_yn: bool,
+        prcs_dvsn_include_yesterday: bool,
+    ) -> Self {
+        Self {
+            cano,
+            acnt_prdt_cd,
+            afhr_flpr_yn,
+            ofl_yn: "".to_string(),
+            inqr_dvsn,
+            unpr_dvsn: "01".to_string(),
+            fund_sttl_icld_yn,
+            fncg_amt_auto_rdpt_yn: false,
+            prcs_dvsn_include_yesterday,
+            ctx_area_fk100: "".to_string(),
+            ctx_area_nk100: "".to_string(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 11] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            (
+                "AFHR_FLPR_YN",
+                if self.afhr_flpr_yn { "Y" } else { "N" }.to_string(),
+            ),
+            ("OFL_YN", self.ofl_yn.clone()),
+            ("INQR_DVSN", format!("{}", self.inqr_dvsn)),
+            ("UNPR_DVSN", self.unpr_dvsn.clone()),
+            (
+                "FUND_STTL_ICLD_YN",
+                if self.fund_sttl_icld_yn { "Y" } else { "N" }.to_string(),
+            ),
+            (
+                "FNCG_AMT_AUTO_RDPT_YN",
+                if self.fncg_amt_auto_rdpt_yn { "Y" } else { "N" }.to_string(),
+            ),
+            (
+                "PRCS_DVSN",
+                if self.prcs_dvsn_include_yesterday {
+                    "00"
+                } else {
+                    "01"
+                }
+                .to_string(),
+            ),
+            ("CTX_AREA_FK100", self.ctx_area_fk100.clone()),
+            ("CTX_AREA_NK100", self.ctx_area_nk100.clone()),
+        ]
+    }
+}
+
+/// 매수가능조회[v1_국내주식-007]
+#[derive(Debug, Clone, Getters, CopyGetters, Setters, Serialize)]
+pub struct InquirePsblOrderParameter {
+    /// 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub", set = "pub")]
+    cano: String,
+    /// 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub", set = "pub")]
+    acnt_prdt_cd: String,
+    /// 상품번호(종목번호 6자리, 공란 입력시 매수수량 없이 매수금액만 조회)
+    #[getset(get = "pub", set = "pub")]
+    pdno: String,
+    /// 주문단가(1주당 가격, 시장가 조회시 공란)
+    #[getset(get_copy = "pub", set = "pub")]
+    ord_unpr: Option<Price>,
+    /// 주문구분
+    #[getset(get = "pub", set = "pub")]
+    ord_dvsn: OrderClass,
+    /// CMA평가금액포함여부
+    #[getset(get_copy = "pub", set = "pub")]
+    cma_evlu_amt_icld_yn: bool,
+    /// 해외포함여부
+    #[getset(get_copy = "pub", set = "pub")]
+    ovrs_icld_yn: bool,
+}
+
+impl InquirePsblOrderParameter {
+    pub fn new(
+        cano: String,
+        acnt_prdt_cd: String,
+        pdno: String,
+        ord_unpr: Option<Price>,
+        ord_dvsn: OrderClass,
+        cma_evlu_amt_icld_yn: bool,
+        ovrs_icld_yn: bool,
+    ) -> Self {
+        Self {
+            cano,
+            acnt_prdt_cd,
+            pdno,
+            ord_unpr,
+            ord_dvsn,
+            cma_evlu_amt_icld_yn,
+            ovrs_icld_yn,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 7] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("PDNO", self.pdno.clone()),
+            (
+                "ORD_UNPR",
+                if let Some(price) = self.ord_unpr {
+                    price.into()
+                } else {
+                    "".to_string()
+                },
+            ),
+            ("ORD_DVSN", self.ord_dvsn.clone().into()),
+            (
+                "CMA_EVLU_AMT_ICLD_YN",
+                if self.cma_evlu_amt_icld_yn { "Y" } else { "N" }.to_string(),
+            ),
+            (
+                "OVRS_ICLD_YN",
+                if self.ovrs_icld_yn { "Y" } else { "N" }.to_string(),
+            ),
+        ]
+    }
+}
+
+/// 매도가능수량조회[국내주식-165]
+#[derive(Debug, Clone, Getters, Setters, Serialize)]
+pub struct InquirePsblSellParameter {
+    /// 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub", set = "pub")]
+    cano: String,
+    /// 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub", set = "pub")]
+    acnt_prdt_cd: String,
+    /// 상품번호(종목번호 6자리)
+    #[getset(get = "pub", set = "pub")]
+    pdno: String,
+}
+
+impl InquirePsblSellParameter {
+    pub fn new(cano: String, acnt_prdt_cd: String, pdno: String) -> Self {
+        Self {
+            cano,
+            acnt_prdt_cd,
+            pdno,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 3] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("PDNO", self.pdno.clone()),
+        ]
+    }
+}
+
+/// 주식정정취소가능주문조회[v1_국내주식-004]
+#[derive(Debug, Clone, Getters, Setters, Serialize)]
+pub struct InquirePsblRvsecnclParameter {
+    /// 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub", set = "pub")]
+    cano: String,
+    /// 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub", set = "pub")]
+    acnt_prdt_cd: String,
+    /// 연속조회검색조건100
+    #[getset(get = "pub", set = "pub")]
+    ctx_area_fk100: String,
+    /// 연속조회키100
+    #[getset(get = "pub", set = "pub")]
+    ctx_area_nk100: String,
+    /// 조회구분1(0: 주문, 1: 종목)
+    #[getset(get = "pub", set = "pub")]
+    inqr_dvsn_1: String,
+    /// 조회구분2(0: 전체, 1: 매도, 2: 매수)
+    #[getset(get = "pub", set = "pub")]
+    inqr_dvsn_2: String,
+}
+
+impl InquirePsblRvsecnclParameter {
+    pub fn new(
+        cano: String,
+        acnt_prdt_cd: String,
+        direction: Option<Direction>,
+        ctx_area_fk100: String,
+        ctx_area_nk100: String,
+    ) -> Self {
+        Self {
+            cano,
+            acnt_prdt_cd,
+            ctx_area_fk100,
+            ctx_area_nk100,
+            inqr_dvsn_1: "0".to_string(),
+            inqr_dvsn_2: match direction {
+                None => "0",
+                Some(Direction::Ask) => "1",
+                Some(Direction::Bid) => "2",
+            }
+            .to_string(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 6] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("CTX_AREA_FK100", self.ctx_area_fk100.clone()),
+            ("CTX_AREA_NK100", self.ctx_area_nk100.clone()),
+            ("INQR_DVSN_1", self.inqr_dvsn_1.clone()),
+            ("INQR_DVSN_2", self.inqr_dvsn_2.clone()),
+        ]
+    }
+}
+
+/// 주식일별주문체결조회[v1_국내주식-005]
+#[derive(Debug, Clone, Getters, Setters, Serialize)]
+pub struct InquireDailyCcldParameter {
+    /// 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub", set = "pub")]
+    cano: String,
+    /// 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub", set = "pub")]
+    acnt_prdt_cd: String,
+    /// 조회시작일자(YYYYMMDD)
+    #[getset(get = "pub", set = "pub")]
+    inqr_strt_dt: String,
+    /// 조회종료일자(YYYYMMDD)
+    #[getset(get = "pub", set = "pub")]
+    inqr_end_dt: String,
+    /// 매도매수구분코드(00: 전체, 01: 매도, 02: 매수)
+    #[getset(get = "pub", set = "pub")]
+    sll_buy_dvsn_cd: String,
+    /// 조회구분(00: 역순, 01: 정순)
+    #[getset(get = "pub", set = "pub")]
+    inqr_dvsn: String,
+    /// 상품번호(공란: 전체)
+    #[getset(get = "pub", set = "pub")]
+    pdno: String,
+    /// 체결구분
+    #[getset(get = "pub", set = "pub")]
+    ccld_dvsn: ExecutionClass,
+    /// 주문채번지점번호(공란: 전체)
+    #[getset(get = "pub", set = "pub")]
+    ord_gno_brno: String,
+    /// 주문번호(공란: 전체)
+    #[getset(get = "pub", set = "pub")]
+    odno: String,
+    /// 조회구분3(00: 전체, 01: 현금, 02: 융자, 03: 대출, 04: 대주)
+    #[getset(get = "pub", set = "pub")]
+    inqr_dvsn_3: String,
+    /// 조회구분1(공란: 전체, 1: ELW, 2: 프리보드)
+    #[getset(get = "pub", set = "pub")]
+    inqr_dvsn_1: String,
+    /// 연속조회검색조건100
+    #[getset(get = "pub", set = "pub")]
+    ctx_area_fk100: String,
+    /// 연속조회키100
+    #[getset(get = "pub", set = "pub")]
+    ctx_area_nk100: String,
+}
+
+impl InquireDailyCcldParameter {
+    /// 연속조회키는 공란(최초 조회). 다음 조회는 set_ctx_area_fk100, set_ctx_area_nk100으로 지정
+    pub fn new(
+        cano: String,
+        acnt_prdt_cd: String,
+        inqr_strt_dt: String,
+        inqr_end_dt: String,
+        direction: Option<Direction>,
+        pdno: String,
+        ccld_dvsn: ExecutionClass,
+    ) -> Self {
+        Self {
+            cano,
+            acnt_prdt_cd,
+            inqr_strt_dt,
+            inqr_end_dt,
+            sll_buy_dvsn_cd: match direction {
+                None => "00".to_string(),
+                Some(direction) => direction.to_string(),
+            },
+            inqr_dvsn: "01".to_string(),
+            pdno,
+            ccld_dvsn,
+            ord_gno_brno: "".to_string(),
+            odno: "".to_string(),
+            inqr_dvsn_3: "00".to_string(),
+            inqr_dvsn_1: "".to_string(),
+            ctx_area_fk100: "".to_string(),
+            ctx_area_nk100: "".to_string(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 14] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("INQR_STRT_DT", self.inqr_strt_dt.clone()),
+            ("INQR_END_DT", self.inqr_end_dt.clone()),
+            ("SLL_BUY_DVSN_CD", self.sll_buy_dvsn_cd.clone()),
+            ("INQR_DVSN", self.inqr_dvsn.clone()),
+            ("PDNO", self.pdno.clone()),
+            ("CCLD_DVSN", self.ccld_dvsn.to_string()),
+            ("ORD_GNO_BRNO", self.ord_gno_brno.clone()),
+            ("ODNO", self.odno.clone()),
+            ("INQR_DVSN_3", self.inqr_dvsn_3.clone()),
+            ("INQR_DVSN_1", self.inqr_dvsn_1.clone()),
+            ("CTX_AREA_FK100", self.ctx_area_fk100.clone()),
+            ("CTX_AREA_NK100", self.ctx_area_nk100.clone()),
+        ]
+    }
+}
+
+/// 주식예약주문조회[v1_국내주식-020]
+#[derive(Debug, Clone, Getters, Setters, Serialize)]
+pub struct InquireReserveOrderParameter {
+    /// 예약주문시작일자(YYYYMMDD)
+    #[getset(get = "pub", set = "pub")]
+    rsvn_ord_ord_dt: String,
+    /// 예약주문종료일자(YYYYMMDD)
+    #[getset(get = "pub", set = "pub")]
+    rsvn_ord_end_dt: String,
+    /// 예약주문순번(공란: 전체)
+    #[getset(get = "pub", set = "pub")]
+    rsvn_ord_seq: String,
+    /// 단말매체종류코드(00: 전체)
+    #[getset(get = "pub", set = "pub")]
+    tmnl_mdia_kind_cd: String,
+    /// 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub", set = "pub")]
+    cano: String,
+    /// 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub", set = "pub")]
+    acnt_prdt_cd: String,
+    /// 처리구분코드(0: 전체, 1: 처리내역, 2: 미처리내역)
+    #[getset(get = "pub", set = "pub")]
+    prcs_dvsn_cd: ReservationProcessClass,
+    /// 취소여부(Y: 취소건 포함)
+    #[getset(get = "pub", set = "pub")]
+    cncl_yn: bool,
+    /// 상품번호(공란: 전체)
+    #[getset(get = "pub", set = "pub")]
+    pdno: String,
+    /// 매도매수구분코드(None: 전체)
+    #[getset(get = "pub", set = "pub")]
+    sll_buy_dvsn_cd: Option<Direction>,
+    /// 연속조회검색조건200
+    #[getset(get = "pub", set = "pub")]
+    ctx_area_fk200: String,
+    /// 연속조회키200
+    #[getset(get = "pub", set = "pub")]
+    ctx_area_nk200: String,
+}
+
+impl InquireReserveOrderParameter {
+    /// 취소건 제외, 매도매수 전체, 최초 조회. 나머지 조건은 setter로 지정
+    pub fn new(
+        cano: String,
+        acnt_prdt_cd: String,
+        rsvn_ord_ord_dt: String,
+        rsvn_ord_end_dt: String,
+        prcs_dvsn_cd: ReservationProcessClass,
+        pdno: String,
+    ) -> Self {
+        Self {
+            rsvn_ord_ord_dt,
+            rsvn_ord_end_dt,
+            rsvn_ord_seq: "".to_string(),
+            tmnl_mdia_kind_cd: "00".to_string(),
+            cano,
+            acnt_prdt_cd,
+            prcs_dvsn_cd,
+            cncl_yn: false,
+            pdno,
+            sll_buy_dvsn_cd: None,
+            ctx_area_fk200: "".to_string(),
+            ctx_area_nk200: "".to_string(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 12] {
+        [
+            ("RSVN_ORD_ORD_DT", self.rsvn_ord_ord_dt.clone()),
+            ("RSVN_ORD_END_DT", self.rsvn_ord_end_dt.clone()),
+            ("RSVN_ORD_SEQ", self.rsvn_ord_seq.clone()),
+            ("TMNL_MDIA_KIND_CD", self.tmnl_mdia_kind_cd.clone()),
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("PRCS_DVSN_CD", format!("{}", self.prcs_dvsn_cd)),
+            ("CNCL_YN", if self.cncl_yn { "Y" } else { "N" }.to_string()),
+            ("PDNO", self.pdno.clone()),
+            (
+                "SLL_BUY_DVSN_CD",
+                if let Some(direction) = &self.sll_buy_dvsn_cd {
+                    format!("{}", direction)
+                } else {
+                    "".to_string()
+                },
+            ),
+            ("CTX_AREA_FK200", self.ctx_area_fk200.clone()),
+            ("CTX_AREA_NK200", self.ctx_area_nk200.clone()),
+        ]
+    }
+}
+
+/// 기간별손익일별합산조회[v1_국내주식-052]
+/// 기간별매매손익현황조회[v1_국내주식-060]
+#[derive(Debug, Clone, Getters, Setters, Serialize)]
+pub struct InquirePeriodProfitParameter {
+    /// 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub", set = "pub")]
+    cano: String,
+    /// 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub", set = "pub")]
+    acnt_prdt_cd: String,
+    /// 조회시작일자(YYYYMMDD)
+    #[getset(get = "pub", set = "pub")]
+    inqr_strt_dt: String,
+    /// 조회종료일자(YYYYMMDD)
+    #[getset(get = "pub", set = "pub")]
+    inqr_end_dt: String,
+    /// 상품번호(공란: 전체)
+    #[getset(get = "pub", set = "pub")]
+    pdno: String,
+    /// 정렬구분(00: 최근 순, 01: 과거 순)
+    #[getset(get = "pub", set = "pub")]
+    sort_dvsn: SortClass,
+    /// 조회구분(00)
+    #[getset(get = "pub", set = "pub")]
+    inqr_dvsn: String,
+    /// 잔고구분(00: 전체)
+    #[getset(get = "pub", set = "pub")]
+    cblc_dvsn: String,
+    /// 연속조회검색조건100
+    #[getset(get = "pub", set = "pub")]
+    ctx_area_fk100: String,
+    /// 연속조회키100
+    #[getset(get = "pub", set = "pub")]
+    ctx_area_nk100: String,
+}
+
+impl InquirePeriodProfitParameter {
+    /// 연속조회키는 공란(최초 조회). 다음 조회는 set_ctx_area_fk100, set_ctx_area_nk100으로 지정
+    pub fn new(
+        cano: String,
+        acnt_prdt_cd: String,
+        inqr_strt_dt: String,
+        inqr_end_dt: String,
+        pdno: String,
+        sort_dvsn: SortClass,
+    ) -> Self {
+        Self {
+            cano,
+            acnt_prdt_cd,
+            inqr_strt_dt,
+            inqr_end_dt,
+            pdno,
+            sort_dvsn,
+            inqr_dvsn: "00".to_string(),
+            cblc_dvsn: "00".to_string(),
+            ctx_area_fk100: "".to_string(),
+            ctx_area_nk100: "".to_string(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 10] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("INQR_STRT_DT", self.inqr_strt_dt.clone()),
+            ("INQR_END_DT", self.inqr_end_dt.clone()),
+            ("PDNO", self.pdno.clone()),
+            ("SORT_DVSN", format!("{}", self.sort_dvsn)),
+            ("INQR_DVSN", self.inqr_dvsn.clone()),
+            ("CBLC_DVSN", self.cblc_dvsn.clone()),
+            ("CTX_AREA_FK100", self.ctx_area_fk100.clone()),
+            ("CTX_AREA_NK100", self.ctx_area_nk100.clone()),
+        ]
+    }
+}
+
+/// 주식잔고조회_실현손익[v1_국내주식-041]
+#[derive(Debug, Clone, Getters, CopyGetters, Setters, Serialize)]
+pub struct InquireBalanceRlzPlParameter {
+    /// 주식잔고조회와 동일한 조건
+    #[getset(get = "pub", set = "pub")]
+    balance: InquireBalanceParameter,
+    /// 비용포함여부
+    #[getset(get_copy = "pub", set = "pub")]
+    cost_icld_yn: bool,
+}
+
+impl InquireBalanceRlzPlParameter {
+    pub fn new(balance: InquireBalanceParameter, cost_icld_yn: bool) -> Self {
+        Self {
+            balance,
+            cost_icld_yn,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 12] {
+        let balance = &self.balance;
+        [
+            ("CANO", balance.cano.clone()),
+            ("ACNT_PRDT_CD", balance.acnt_prdt_cd.clone()),
+            (
+                "AFHR_FLPR_YN",
+                if balance.afhr_flpr_yn { "Y" } else { "N" }.to_string(),
+            ),
+            ("OFL_YN", balance.ofl_yn.clone()),
+            ("INQR_DVSN", format!("{}", balance.inqr_dvsn)),
+            ("UNPR_DVSN", balance.unpr_dvsn.clone()),
+            (
+                "FUND_STTL_ICLD_YN",
+                if balance.fund_sttl_icld_yn { "Y" } else { "N" }.to_string(),
+            ),
+            (
+                "FNCG_AMT_AUTO_RDPT_YN",
+                if balance.fncg_amt_auto_rdpt_yn {
+                    "Y"
+                } else {
+                    "N"
+                }
+                .to_string(),
+            ),
+            (
+                "PRCS_DVSN",
+                if balance.prcs_dvsn_include_yesterday {
+                    "00"
+                } else {
+                    "01"
+                }
+                .to_string(),
+            ),
+            (
+                "COST_ICLD_YN",
+                if self.cost_icld_yn { "Y" } else { "N" }.to_string(),
+            ),
+            ("CTX_AREA_FK100", balance.ctx_area_fk100.clone()),
+            ("CTX_AREA_NK100", balance.ctx_area_nk100.clone()),
+        ]
+    }
+}
+
+/// 투자계좌자산현황조회[v1_국내주식-048]
+#[derive(Debug, Clone, Getters, Setters, Serialize)]
+pub struct InquireAccountBalanceParameter {
+    /// 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub", set = "pub")]
+    cano: String,
+    /// 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub", set = "pub")]
+    acnt_prdt_cd: String,
+    /// 조회구분1(공란)
+    #[getset(get = "pub", set = "pub")]
+    inqr_dvsn_1: String,
+    /// 기준가이전일자적용여부(공란)
+    #[getset(get = "pub", set = "pub")]
+    bspr_bf_dt_aply_yn: String,
+}
+
+impl InquireAccountBalanceParameter {
+    pub fn new(cano: String, acnt_prdt_cd: String) -> Self {
+        Self {
+            cano,
+            acnt_prdt_cd,
+            inqr_dvsn_1: "".to_string(),
+            bspr_bf_dt_aply_yn: "".to_string(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 4] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("INQR_DVSN_1", self.inqr_dvsn_1.clone()),
+            ("BSPR_BF_DT_APLY_YN", self.bspr_bf_dt_aply_yn.clone()),
+        ]
+    }
+}
@@ -1,6 +1,6 @@
 use crate::types::{
-    BelongClassCode, CustomerType, MarketCode, PeriodCode, Price, ProductTypeCode, ShareClassCode,
-    TargetClassCode, TargetExeceptClassCode, TrId,
+    BelongClassCode, ChartPeriod, CustomerType, MarketCode, PeriodCode, Price, ProductTypeCode,
+    ShareClassCode, TargetClassCode, TargetExeceptClassCode, TrId,
 };
 use getset::{CopyGetters, Getters, Setters};
 use serde::{Deserialize, Serialize};
@@ -75,6 +75,236 @@ impl DailyPriceParameter {
     }
 }
 
+#[derive(Debug, Clone, Getters, Setters, Serialize)]
+pub struct CurrentPriceParameter {
+    /// FID 조건 시장 분류 코드
+    #[getset(get = "pub", set = "pub")]
+    fid_cond_mrkt_div_code: MarketCode,
+    /// FID 입력 종목코드
+    #[getset(get = "pub", set = "pub")]
+    fid_input_iscd: String,
+}
+impl CurrentPriceParameter {
+    pub fn new(market_code: MarketCode, shortcode: String) -> Self {
+        Self {
+            fid_cond_mrkt_div_code: market_code,
+            fid_input_iscd: shortcode,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 2] {
+        [
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                format!("{}", self.fid_cond_mrkt_div_code),
+            ),
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Getters, Setters, Serialize)]
+pub struct TimeItemConclusionParameter {
+    /// FID 조건 시장 분류 코드
+    #[getset(get = "pub", set = "pub")]
+    fid_cond_mrkt_div_code: MarketCode,
+    /// FID 입력 종목코드
+    #[getset(get = "pub", set = "pub")]
+    fid_input_iscd: String,
+    /// FID 입력 시간1(HHMMSS, 이 시각 이전 체결부터 조회)
+    #[getset(get = "pub", set = "pub")]
+    fid_input_hour_1: String,
+}
+impl TimeItemConclusionParameter {
+    pub fn new(market_code: MarketCode, shortcode: String, hour: String) -> Self {
+        Self {
+            fid_cond_mrkt_div_code: market_code,
+            fid_input_iscd: shortcode,
+            fid_input_hour_1: hour,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 3] {
+        [
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                format!("{}", self.fid_cond_mrkt_div_code),
+            ),
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+            ("FID_INPUT_HOUR_1", self.fid_input_hour_1.clone()),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Getters, CopyGetters, Setters, Serialize)]
+pub struct TimeItemChartPriceParameter {
+    /// FID 기타 구분 코드(공백)
+    #[getset(get = "pub", set = "pub")]
+    fid_etc_cls_code: String,
+    /// FID 조건 시장 분류 코드
+    #[getset(get = "pub", set = "pub")]
+    fid_cond_mrkt_div_code: MarketCode,
+    /// FID 입력 종목코드
+    #[getset(get = "pub", set = "pub")]
+    fid_input_iscd: String,
+    /// FID 입력 시간1(HHMMSS, 이 시각 이전 분봉부터 조회)
+    #[getset(get = "pub", set = "pub")]
+    fid_input_hour_1: String,
+    /// FID 과거 데이터 포함 여부(장 시작 이전이면 전일 분봉까지 조회)
+    #[getset(get_copy = "pub", set = "pub")]
+    fid_pw_data_incu_yn: bool,
+}
+impl TimeItemChartPriceParameter {
+    pub fn new(
+        market_code: MarketCode,
+        shortcode: String,
+        hour: String,
+        include_past_data: bool,
+    ) -> Self {
+        Self {
+            fid_etc_cls_code: "".to_string(),
+            fid_cond_mrkt_div_code: market_code,
+            fid_input_iscd: shortcode,
+            fid_input_hour_1: hour,
+            fid_pw_data_incu_yn: include_past_data,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 5] {
+        [
+            ("FID_ETC_CLS_CODE", self.fid_etc_cls_code.clone()),
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                format!("{}", self.fid_cond_mrkt_div_code),
+            ),
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+            ("FID_INPUT_HOUR_1", self.fid_input_hour_1.clone()),
+            (
+                "FID_PW_DATA_INCU_YN",
+                if self.fid_pw_data_incu_yn { "Y" } else { "N" }.to_string(),
+            ),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Getters, CopyGetters, Setters, Serialize)]
+pub struct TimeDailyChartPriceParameter {
+    /// FID 조건 시장 분류 코드
+    #[getset(get = "pub", set = "pub")]
+    fid_cond_mrkt_div_code: MarketCode,
+    /// FID 입력 종목코드
+    #[getset(get = "pub", set = "pub")]
+    fid_input_iscd: String,
+    /// FID 입력 시간1(HHMMSS, 이 시각 이전 분봉부터 조회)
+    #[getset(get = "pub", set = "pub")]
+    fid_input_hour_1: String,
+    /// FID 입력 날짜1(YYYYMMDD)
+    #[getset(get = "pub", set = "pub")]
+    fid_input_date_1: String,
+    /// FID 과거 데이터 포함 여부(장 시작 이전이면 전일 분봉까지 조회)
+    #[getset(get_copy = "pub", set = "pub")]
+    fid_pw_data_incu_yn: bool,
+    /// FID 허봉 포함 여부(공백)
+    #[getset(get = "pub", set = "pub")]
+    fid_fake_tick_incu_yn: String,
+}
+impl TimeDailyChartPriceParameter {
+    pub fn new(
+        market_code: MarketCode,
+        shortcode: String,
+        date: String,
+        hour: String,
+        include_past_data: bool,
+    ) -> Self {
+        Self {
+            fid_cond_mrkt_div_code: market_code,
+            fid_input_iscd: shortcode,
+            fid_input_hour_1: hour,
+            fid_input_date_1: date,
+            fid_pw_data_incu_yn: include_past_data,
+            fid_fake_tick_incu_yn: "".to_string(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 6] {
+        [
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                format!("{}", self.fid_cond_mrkt_div_code),
+            ),
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+            ("FID_INPUT_HOUR_1", self.fid_input_hour_1.clone()),
+            ("FID_INPUT_DATE_1", self.fid_input_date_1.clone()),
+            (
+                "FID_PW_DATA_INCU_YN",
+                if self.fid_pw_data_incu_yn { "Y" } else { "N" }.to_string(),
+            ),
+            ("FID_FAKE_TICK_INCU_YN", self.fid_fake_tick_incu_yn.clone()),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Getters, CopyGetters, Setters, Serialize)]
+pub struct DailyItemChartPriceParameter {
+    /// FID 조건 시장 분류 코드
+    #[getset(get = "pub", set = "pub")]
+    fid_cond_mrkt_div_code: MarketCode,
+    /// FID 입력 종목코드
+    #[getset(get = "pub", set = "pub")]
+    fid_input_iscd: String,
+    /// FID 입력 날짜1(조회 시작일자, YYYYMMDD)
+    #[getset(get = "pub", set = "pub")]
+    fid_input_date_1: String,
+    /// FID 입력 날짜2(조회 종료일자, YYYYMMDD)
+    #[getset(get = "pub", set = "pub")]
+    fid_input_date_2: String,
+    /// FID 기간 분류 코드
+    #[getset(get = "pub", set = "pub")]
+    fid_period_div_code: ChartPeriod,
+    /// FID 수정주가 원주가 가격(수정주가 반영: 0, 수정주가 미반영: 1)
+    #[getset(get_copy = "pub", set = "pub")]
+    fid_org_adj_prc: u8,
+}
+impl DailyItemChartPriceParameter {
+    pub fn new(
+        market_code: MarketCode,
+        shortcode: String,
+        start: String,
+        end: String,
+        period: ChartPeriod,
+        is_adjust_price: bool,
+    ) -> Self {
+        Self {
+            fid_cond_mrkt_div_code: market_code,
+            fid_input_iscd: shortcode,
+            fid_input_date_1: start,
+            fid_input_date_2: end,
+            fid_period_div_code: period,
+            fid_org_adj_prc: match is_adjust_price {
+                true => 0,
+                false => 1,
+            },
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 6] {
+        [
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                format!("{}", self.fid_cond_mrkt_div_code),
+            ),
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+            ("FID_INPUT_DATE_1", self.fid_input_date_1.clone()),
+            ("FID_INPUT_DATE_2", self.fid_input_date_2.clone()),
+            (
+                "FID_PERIOD_DIV_CODE",
+                format!("{}", self.fid_period_div_code),
+            ),
+            ("FID_ORG_ADJ_PRC", format!("{}", self.fid_org_adj_prc)),
+        ]
+    }
+}
+
 #[derive(Debug, Clone, Getters, CopyGetters, Setters, Serialize)]
 pub struct VolumeRankParameter {
     /// 조건 시장 분류 코드(J)
@@ -19,6 +19,7 @@ pub mod Header {
 
 pub mod Body {
     use super::Output;
+    use crate::types::AccountAssetClass;
     use getset::Getters;
     use serde::{Deserialize, Serialize};
 
@@ -38,13 +39,203 @@ pub mod Body {
         #[getset(get = "pub")]
         output: Option<Output::Order>,
     }
+    impl Order {
+        pub(crate) fn new(
+            rt_cd: String,
+            msg_cd: String,
+            msg1: String,
+            output: Option<Output::Order>,
+        ) -> Self {
+            Self {
+                rt_cd,
+                msg_cd,
+                msg1,
+                output,
+            }
+        }
+    }
 
     /// 주식정정취소가능주문조회
     #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
     pub struct InquirePsblRvsecncl {
         /// 0: 성공, 0 이외의 값: 실패
         #[getset(get = "pub")]
-        tr_cd: String,
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 연속조회검색조건100
+        #[getset(get = "pub")]
+        ctx_area_fk100: Option<String>,
+        /// 연속조회키100
+        #[getset(get = "pub")]
+        ctx_area_nk100: Option<String>,
+        /// 응답 상세
+        #[getset(get = "pub")]
+        output: Option<Vec<Output::InquirePsblRvsecncl>>,
+        /// 연속 거래 여부(응답 헤더의 tr_cont)
+        #[getset(get = "pub")]
+        #[serde(skip)]
+        tr_cont: String,
+    }
+    impl InquirePsblRvsecncl {
+        pub(crate) fn set_tr_cont(&mut self, tr_cont: String) {
+            self.tr_cont = tr_cont;
+        }
+
+        /// 다음 데이터가 있는지 여부(tr_cont가 F 또는 M)
+        pub fn has_next(&self) -> bool {
+            matches!(self.tr_cont.as_str(), "F" | "M")
+        }
+    }
+
+    /// 주식잔고조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquireBalance {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 연속조회검색조건100
+        #[getset(get = "pub")]
+        ctx_area_fk100: Option<String>,
+        /// 연속조회키100
+        #[getset(get = "pub")]
+        ctx_area_nk100: Option<String>,
+        /// 응답 상세1(보유종목)
+        #[getset(get = "pub")]
+        output1: Option<Vec<Output::InquireBalance>>,
+        /// 응답 상세2(계좌 요약)
+        #[getset(get = "pub")]
+        output2: Option<Vec<Output::InquireBalanceSummary>>,
+        /// 연속 거래 여부(응답 헤더의 tr_cont)
+        #[getset(get = "pub")]
+        #[serde(skip)]
+        tr_cont: String,
+    }
+    impl InquireBalance {
+        pub(crate) fn set_tr_cont(&mut self, tr_cont: String) {
+            self.tr_cont = tr_cont;
+        }
+
+        /// 다음 데이터가 있는지 여부(tr_cont가 F 또는 M)
+        pub fn has_next(&self) -> bool {
+            matches!(self.tr_cont.as_str(), "F" | "M")
+        }
+    }
+
+    /// 매수가능조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquirePsblOrder {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 응답 상세
+        #[getset(get = "pub")]
+        output: Option<Output::InquirePsblOrder>,
+    }
+
+    /// 매도가능수량조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquirePsblSell {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 응답 상세
+        #[getset(get = "pub")]
+        output: Option<Output::InquirePsblSell>,
+    }
+
+    /// 주식예약주문
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct ReserveOrder {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 응답 상세
+        #[getset(get = "pub")]
+        output: Option<Output::ReserveOrder>,
+    }
+    impl ReserveOrder {
+        pub(crate) fn new(
+            rt_cd: String,
+            msg_cd: String,
+            msg1: String,
+            output: Option<Output::ReserveOrder>,
+        ) -> Self {
+            Self {
+                rt_cd,
+                msg_cd,
+                msg1,
+                output,
+            }
+        }
+    }
+
+    /// 주식예약주문정정취소
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct ReserveCorrection {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 응답 상세
+        #[getset(get = "pub")]
+        output: Option<Output::ReserveCorrection>,
+    }
+    impl ReserveCorrection {
+        pub(crate) fn new(
+            rt_cd: String,
+            msg_cd: String,
+            msg1: String,
+            output: Option<Output::ReserveCorrection>,
+        ) -> Self {
+            Self {
+                rt_cd,
+                msg_cd,
+                msg1,
+                output,
+            }
+        }
+    }
+
+    /// 주식일별주문체결조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquireDailyCcld {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
         /// 응답코드
         #[getset(get = "pub")]
         msg_cd: String,
@@ -57,9 +248,321 @@ pub mod Body {
         /// 연속조회키100
         #[getset(get = "pub")]
         ctx_area_nk100: Option<String>,
+        /// 주문별 체결내역
+        #[getset(get = "pub")]
+        output1: Option<Vec<Output::InquireDailyCcld>>,
+        /// 합계
+        #[getset(get = "pub")]
+        output2: Option<Output::InquireDailyCcldSummary>,
+        /// 연속 거래 여부(응답 헤더의 tr_cont)
+        #[getset(get = "pub")]
+        #[serde(skip)]
+        tr_cont: String,
+    }
+    impl InquireDailyCcld {
+        pub(crate) fn set_tr_cont(&mut self, tr_cont: String) {
+            self.tr_cont = tr_cont;
+        }
+
+        /// 다음 데이터가 있는지 여부(tr_cont가 F 또는 M)
+        pub fn has_next(&self) -> bool {
+            matches!(self.tr_cont.as_str(), "F" | "M")
+        }
+    }
+
+    /// 주식예약주문조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquireReserveOrder {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 연속조회검색조건200
+        #[getset(get = "pub")]
+        ctx_area_fk200: Option<String>,
+        /// 연속조회키200
+        #[getset(get = "pub")]
+        ctx_area_nk200: Option<String>,
         /// 응답 상세
         #[getset(get = "pub")]
-        output: Output::InquirePsblRvsecncl,
+        output: Option<Vec<Output::InquireReserveOrder>>,
+        /// 연속 거래 여부(응답 헤더의 tr_cont)
+        #[getset(get = "pub")]
+        #[serde(skip)]
+        tr_cont: String,
+    }
+    impl InquireReserveOrder {
+        pub(crate) fn set_tr_cont(&mut self, tr_cont: String) {
+            self.tr_cont = tr_cont;
+        }
+
+        /// 다음 데이터가 있는지 여부(tr_cont가 F 또는 M)
+        pub fn has_next(&self) -> bool {
+            matches!(self.tr_cont.as_str(), "F" | "M")
+        }
+    }
+
+    /// 기간별손익일별합산조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquirePeriodProfit {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 연속조회검색조건100
+        #[getset(get = "pub")]
+        ctx_area_fk100: Option<String>,
+        /// 연속조회키100
+        #[getset(get = "pub")]
+        ctx_area_nk100: Option<String>,
+        /// 응답 상세1(일별 손익)
+        #[getset(get = "pub")]
+        output1: Option<Vec<Output::PeriodProfit>>,
+        /// 응답 상세2(기간 합계)
+        #[getset(get = "pub")]
+        output2: Option<Output::PeriodProfitSummary>,
+        /// 연속 거래 여부(응답 헤더의 tr_cont)
+        #[getset(get = "pub")]
+        #[serde(skip)]
+        tr_cont: String,
+    }
+    impl InquirePeriodProfit {
+        pub(crate) fn set_tr_cont(&mut self, tr_cont: String) {
+            self.tr_cont = tr_cont;
+        }
+
+        /// 다음 데이터가 있는지 여부(tr_cont가 F 또는 M)
+        pub fn has_next(&self) -> bool {
+            matches!(self.tr_cont.as_str(), "F" | "M")
+        }
+    }
+
+    /// 기간별매매손익현황조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquirePeriodTradeProfit {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 연속조회검색조건100
+        #[getset(get = "pub")]
+        ctx_area_fk100: Option<String>,
+        /// 연속조회키100
+        #[getset(get = "pub")]
+        ctx_area_nk100: Option<String>,
+        /// 응답 상세1(일별 종목별 매매손익)
+        #[getset(get = "pub")]
+        output1: Option<Vec<Output::PeriodTradeProfit>>,
+        /// 응답 상세2(기간 합계)
+        #[getset(get = "pub")]
+        output2: Option<Output::PeriodProfitSummary>,
+        /// 연속 거래 여부(응답 헤더의 tr_cont)
+        #[getset(get = "pub")]
+        #[serde(skip)]
+        tr_cont: String,
+    }
+    impl InquirePeriodTradeProfit {
+        pub(crate) fn set_tr_cont(&mut self, tr_cont: String) {
+            self.tr_cont = tr_cont;
+        }
+
+        /// 다음 데이터가 있는지 여부(tr_cont가 F 또는 M)
+        pub fn has_next(&self) -> bool {
+            matches!(self.tr_cont.as_str(), "F" | "M")
+        }
+    }
+
+    /// 주식잔고조회_실현손익
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquireBalanceRlzPl {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 연속조회검색조건100
+        #[getset(get = "pub")]
+        ctx_area_fk100: Option<String>,
+        /// 연속조회키100
+        #[getset(get = "pub")]
+        ctx_area_nk100: Option<String>,
+        /// 응답 상세1(보유종목)
+        #[getset(get = "pub")]
+        output1: Option<Vec<Output::InquireBalance>>,
+        /// 응답 상세2(계좌 요약 및 실현손익)
+        #[getset(get = "pub")]
+        output2: Option<Vec<Output::InquireBalanceRlzPlSummary>>,
+        /// 연속 거래 여부(응답 헤더의 tr_cont)
+        #[getset(get = "pub")]
+        #[serde(skip)]
+        tr_cont: String,
+    }
+    impl InquireBalanceRlzPl {
+        pub(crate) fn set_tr_cont(&mut self, tr_cont: String) {
+            self.tr_cont = tr_cont;
+        }
+
+        /// 다음 데이터가 있는지 여부(tr_cont가 F 또는 M)
+        pub fn has_next(&self) -> bool {
+            matches!(self.tr_cont.as_str(), "F" | "M")
+        }
+    }
+
+    /// 투자계좌자산현황조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquireAccountBalance {
+        /// 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        rt_cd: String,
+        /// 응답코드
+        #[getset(get = "pub")]
+        msg_cd: String,
+        /// 응답메시지
+        #[getset(get = "pub")]
+        msg1: String,
+        /// 응답 상세1(자산구분별, AccountAssetClass 순서)
+        #[getset(get = "pub")]
+        output1: Option<Vec<Output::AccountAsset>>,
+        /// 응답 상세2(계좌 합계)
+        #[getset(get = "pub")]
+        output2: Option<Output::AccountAssetSummary>,
+    }
+    impl InquireAccountBalance {
+        /// 자산구분에 해당하는 output1 항목
+        pub fn asset(&self, class: AccountAssetClass) -> Option<&Output::AccountAsset> {
+            self.output1.as_ref()?.get(class as usize)
+        }
+    }
+
+    /// 연속조회 응답
+    pub(crate) trait Continued {
+        /// 실패 응답(rt_cd != 0)이면 (응답코드, 응답메시지)
+        fn failure(&self) -> Option<(&str, &str)>;
+        /// 다음 데이터가 있는지 여부
+        fn has_next(&self) -> bool;
+        /// 다음 조회에 넣을 (연속조회검색조건, 연속조회키)
+        fn next_keys(&self) -> (String, String);
+    }
+
+    impl Continued for InquirePsblRvsecncl {
+        fn failure(&self) -> Option<(&str, &str)> {
+            (self.rt_cd != "0").then_some((&self.msg_cd, &self.msg1))
+        }
+
+        fn has_next(&self) -> bool {
+            InquirePsblRvsecncl::has_next(self)
+        }
+
+        fn next_keys(&self) -> (String, String) {
+            (
+                self.ctx_area_fk100.clone().unwrap_or_default(),
+                self.ctx_area_nk100.clone().unwrap_or_default(),
+            )
+        }
+    }
+
+    impl Continued for InquireBalance {
+        fn failure(&self) -> Option<(&str, &str)> {
+            (self.rt_cd != "0").then_some((&self.msg_cd, &self.msg1))
+        }
+
+        fn has_next(&self) -> bool {
+            InquireBalance::has_next(self)
+        }
+
+        fn next_keys(&self) -> (String, String) {
+            (
+                self.ctx_area_fk100.clone().unwrap_or_default(),
+                self.ctx_area_nk100.clone().unwrap_or_default(),
+            )
+        }
+    }
+
+    impl Continued for InquireDailyCcld {
+        fn failure(&self) -> Option<(&str, &str)> {
+            (self.rt_cd != "0").then_some((&self.msg_cd, &self.msg1))
+        }
+
+        fn has_next(&self) -> bool {
+            InquireDailyCcld::has_next(self)
+        }
+
+        fn next_keys(&self) -> (String, String) {
+            (
+                self.ctx_area_fk100.clone().unwrap_or_default(),
+                self.ctx_area_nk100.clone().unwrap_or_default(),
+            )
+        }
+    }
+
+    impl Continued for InquireReserveOrder {
+        fn failure(&self) -> Option<(&str, &str)> {
+            (self.rt_cd != "0").then_some((&self.msg_cd, &self.msg1))
+        }
+
+        fn has_next(&self) -> bool {
+            InquireReserveOrder::has_next(self)
+        }
+
+        fn next_keys(&self) -> (String, String) {
+            (
+                self.ctx_area_fk200.clone().unwrap_or_default(),
+                self.ctx_area_nk200.clone().unwrap_or_default(),
+            )
+        }
+    }
+
+    impl Continued for InquirePeriodProfit {
+        fn failure(&self) -> Option<(&str, &str)> {
+            (self.rt_cd != "0").then_some((&self.msg_cd, &self.msg1))
+        }
+
+        fn has_next(&self) -> bool {
+            InquirePeriodProfit::has_next(self)
+        }
+
+        fn next_keys(&self) -> (String, String) {
+            (
+                self.ctx_area_fk100.clone().unwrap_or_default(),
+                self.ctx_area_nk100.clone().unwrap_or_default(),
+            )
+        }
+    }
+
+    impl Continued for InquirePeriodTradeProfit {
+        fn failure(&self) -> Option<(&str, &str)> {
+            (self.rt_cd != "0").then_some((&self.msg_cd, &self.msg1))
+        }
+
+        fn has_next(&self) -> bool {
+            InquirePeriodTradeProfit::has_next(self)
+        }
+
+        fn next_keys(&self) -> (String, String) {
+            (
+                self.ctx_area_fk100.clone().unwrap_or_default(),
+                self.ctx_area_nk100.clone().unwrap_or_default(),
+            )
+        }
     }
 }
 
@@ -82,6 +585,15 @@ pub mod Output {
         #[getset(get = "pub")]
         ord_tmd: String,
     }
+    impl Order {
+        pub(crate) fn new(krx_fwdg_ord_orgno: String, odno: String, ord_tmd: String) -> Self {
+            Self {
+                krx_fwdg_ord_orgno,
+                odno,
+                ord_tmd,
+            }
+        }
+    }
 
     /// 주식정정취소가능주문조회
     #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
@@ -134,5 +646,744 @@ pub mod Output {
         /// 운용사지정주문번호(주문번호(운용사 통한 주문))
         #[getset(get = "pub")]
         mgco_aptm_odno: String,
+        /// 거래소ID구분코드(KRX, NXT, SOR)
+        #[serde(default)]
+        #[getset(get = "pub")]
+        excg_id_dvsn_cd: String,
+    }
+
+    /// 주식잔고조회 - 보유종목
+    /// 주식잔고조회_실현손익 응답에 없는 필드는 빈 값
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct InquireBalance {
+        /// 상품번호(종목번호(뒷 6자리))
+        #[getset(get = "pub")]
+        pdno: String,
+        /// 상품명(종목명)
+        #[getset(get = "pub")]
+        prdt_name: String,
+        /// 매매구분명(매수매도구분)
+        #[getset(get = "pub")]
+        trad_dvsn_name: String,
+        /// 전일매수수량
+        #[getset(get = "pub")]
+        bfdy_buy_qty: String,
+        /// 전일매도수량
+        #[getset(get = "pub")]
+        bfdy_sll_qty: String,
+        /// 금일매수수량
+        #[getset(get = "pub")]
+        thdt_buyqty: String,
+        /// 금일매도수량
+        #[getset(get = "pub")]
+        thdt_sll_qty: String,
+        /// 보유수량
+        #[getset(get = "pub")]
+        hldg_qty: String,
+        /// 주문가능수량
+        #[getset(get = "pub")]
+        ord_psbl_qty: String,
+        /// 매입평균가격(매입금액 / 보유수량)
+        #[getset(get = "pub")]
+        pchs_avg_pric: String,
+        /// 매입금액
+        #[getset(get = "pub")]
+        pchs_amt: String,
+        /// 현재가
+        #[getset(get = "pub")]
+        prpr: String,
+        /// 평가금액
+        #[getset(get = "pub")]
+        evlu_amt: String,
+        /// 평가손익금액(평가금액 - 매입금액)
+        #[getset(get = "pub")]
+        evlu_pfls_amt: String,
+        /// 평가손익율
+        #[getset(get = "pub")]
+        evlu_pfls_rt: String,
+        /// 평가수익율
+        #[getset(get = "pub")]
+        evlu_erng_rt: String,
+        /// 대출일자
+        #[getset(get = "pub")]
+        loan_dt: String,
+        /// 대출금액
+        #[getset(get = "pub")]
+        loan_amt: String,
+        /// 대주매각대금
+        #[getset(get = "pub")]
+        stln_slng_chgs: String,
+        /// 만기일자
+        #[getset(get = "pub")]
+        expd_dt: String,
+        /// 등락율
+        #[getset(get = "pub")]
+        fltt_rt: String,
+        /// 전일대비증감
+        #[getset(get = "pub")]
+        bfdy_cprs_icdc: String,
+        /// 종목증거금율명
+        #[getset(get = "pub")]
+        item_mgna_rt_name: String,
+        /// 보증금율명
+        #[getset(get = "pub")]
+        grta_rt_name: String,
+        /// 대용가격
+        #[getset(get = "pub")]
+        sbst_pric: String,
+        /// 주식대출단가
+        #[getset(get = "pub")]
+        stck_loan_unpr: String,
+    }
+
+    /// 주식잔고조회 - 계좌 요약
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquireBalanceSummary {
+        /// 예수금총금액
+        #[getset(get = "pub")]
+        dnca_tot_amt: String,
+        /// 익일정산금액(D+1 예수금)
+        #[getset(get = "pub")]
+        nxdy_excc_amt: String,
+        /// 가수도정산금액(D+2 예수금)
+        #[getset(get = "pub")]
+        prvs_rcdl_excc_amt: String,
+        /// CMA평가금액
+        #[getset(get = "pub")]
+        cma_evlu_amt: String,
+        /// 전일매수금액
+        #[getset(get = "pub")]
+        bfdy_buy_amt: String,
+        /// 금일매수금액
+        #[getset(get = "pub")]
+        thdt_buy_amt: String,
+        /// 익일자동상환금액
+        #[getset(get = "pub")]
+        nxdy_auto_rdpt_amt: String,
+        /// 전일매도금액
+        #[getset(get = "pub")]
+        bfdy_sll_amt: String,
+        /// 금일매도금액
+        #[getset(get = "pub")]
+        thdt_sll_amt: String,
+        /// D+2자동상환금액
+        #[getset(get = "pub")]
+        d2_auto_rdpt_amt: String,
+        /// 전일제비용금액
+        #[getset(get = "pub")]
+        bfdy_tlex_amt: String,
+        /// 금일제비용금액
+        #[getset(get = "pub")]
+        thdt_tlex_amt: String,
+        /// 총대출금액
+        #[getset(get = "pub")]
+        tot_loan_amt: String,
+        /// 유가평가금액
+        #[getset(get = "pub")]
+        scts_evlu_amt: String,
+        /// 총평가금액(유가증권 평가금액 합계금액 + D+2 예수금)
+        #[getset(get = "pub")]
+        tot_evlu_amt: String,
+        /// 순자산금액
+        #[getset(get = "pub")]
+        nass_amt: String,
+        /// 융자금자동상환여부
+        #[getset(get = "pub")]
+        fncg_gld_auto_rdpt_yn: String,
+        /// 매입금액합계금액
+        #[getset(get = "pub")]
+        pchs_amt_smtl_amt: String,
+        /// 평가금액합계금액(유가증권 평가금액 합계금액)
+        #[getset(get = "pub")]
+        evlu_amt_smtl_amt: String,
+        /// 평가손익합계금액
+        #[getset(get = "pub")]
+        evlu_pfls_smtl_amt: String,
+        /// 총대주매각대금
+        #[getset(get = "pub")]
+        tot_stln_slng_chgs: String,
+        /// 전일총자산평가금액
+        #[getset(get = "pub")]
+        bfdy_tot_asst_evlu_amt: String,
+        /// 자산증감액
+        #[getset(get = "pub")]
+        asst_icdc_amt: String,
+        /// 자산증감수익율
+        #[getset(get = "pub")]
+        asst_icdc_erng_rt: String,
+    }
+
+    /// 매수가능조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquirePsblOrder {
+        /// 주문가능현금
+        #[getset(get = "pub")]
+        ord_psbl_cash: String,
+        /// 주문가능대용
+        #[getset(get = "pub")]
+        ord_psbl_sbst: String,
+        /// 재사용가능금액
+        #[getset(get = "pub")]
+        ruse_psbl_amt: String,
+        /// 펀드환매대금
+        #[getset(get = "pub")]
+        fund_rpch_chgs: String,
+        /// 가능수량계산단가
+        #[getset(get = "pub")]
+        psbl_qty_calc_unpr: String,
+        /// 미수없는매수금액
+        #[getset(get = "pub")]
+        nrcvb_buy_amt: String,
+        /// 미수없는매수수량
+        #[getset(get = "pub")]
+        nrcvb_buy_qty: String,
+        /// 최대매수금액
+        #[getset(get = "pub")]
+        max_buy_amt: String,
+        /// 최대매수수량
+        #[getset(get = "pub")]
+        max_buy_qty: String,
+        /// CMA평가금액
+        #[getset(get = "pub")]
+        cma_evlu_amt: String,
+        /// 해외재사용금액원화
+        #[getset(get = "pub")]
+        ovrs_re_use_amt_wcrc: String,
+        /// 주문가능외화금액원화
+        #[getset(get = "pub")]
+        ord_psbl_frcr_amt_wcrc: String,
+    }
+
+    /// 매도가능수량조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquirePsblSell {
+        /// 상품번호
+        #[getset(get = "pub")]
+        pdno: String,
+        /// 상품명
+        #[getset(get = "pub")]
+        prdt_name: String,
+        /// 매수수량
+        #[getset(get = "pub")]
+        buy_qty: String,
+        /// 매도수량
+        #[getset(get = "pub")]
+        sll_qty: String,
+        /// 잔고수량
+        #[getset(get = "pub")]
+        cblc_qty: String,
+        /// 비저축수량
+        #[getset(get = "pub")]
+        nsvg_qty: String,
+        /// 주문가능수량
+        #[getset(get = "pub")]
+        ord_psbl_qty: String,
+        /// 매입평균가격
+        #[getset(get = "pub")]
+        pchs_avg_pric: String,
+        /// 매입금액
+        #[getset(get = "pub")]
+        pchs_amt: String,
+        /// 현재가
+        #[getset(get = "pub")]
+        now_pric: String,
+        /// 평가금액
+        #[getset(get = "pub")]
+        evlu_amt: String,
+        /// 평가손익금액
+        #[getset(get = "pub")]
+        evlu_pfls_amt: String,
+        /// 평가손익율
+        #[getset(get = "pub")]
+        evlu_pfls_rt: String,
+    }
+
+    /// 주식예약주문
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct ReserveOrder {
+        /// 예약주문순번
+        #[getset(get = "pub")]
+        rsvn_ord_seq: String,
+    }
+    impl ReserveOrder {
+        pub(crate) fn new(rsvn_ord_seq: String) -> Self {
+            Self { rsvn_ord_seq }
+        }
+    }
+
+    /// 주식예약주문정정취소
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct ReserveCorrection {
+        /// 정상처리여부
+        #[getset(get = "pub")]
+        nrml_prcs_yn: String,
+    }
+    impl ReserveCorrection {
+        pub(crate) fn new(nrml_prcs_yn: String) -> Self {
+            Self { nrml_prcs_yn }
+        }
+    }
+
+    /// 주식일별주문체결조회 - 주문별 체결내역
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct InquireDailyCcld {
+        /// 주문일자(YYYYMMDD)
+        #[getset(get = "pub")]
+        ord_dt: String,
+        /// 주문채번지점번호
+        #[getset(get = "pub")]
+        ord_gno_brno: String,
+        /// 주문번호
+        #[getset(get = "pub")]
+        odno: String,
+        /// 원주문번호
+        #[getset(get = "pub")]
+        orgn_odno: String,
+        /// 주문구분명
+        #[getset(get = "pub")]
+        ord_dvsn_name: String,
+        /// 매도매수구분코드(01: 매도, 02: 매수)
+        #[getset(get = "pub")]
+        sll_buy_dvsn_cd: String,
+        /// 매도매수구분코드명
+        #[getset(get = "pub")]
+        sll_buy_dvsn_cd_name: String,
+        /// 상품번호
+        #[getset(get = "pub")]
+        pdno: String,
+        /// 상품명
+        #[getset(get = "pub")]
+        prdt_name: String,
+        /// 주문수량
+        #[getset(get = "pub")]
+        ord_qty: String,
+        /// 주문단가
+        #[getset(get = "pub")]
+        ord_unpr: String,
+        /// 주문시각(HHMMSS)
+        #[getset(get = "pub")]
+        ord_tmd: String,
+        /// 총체결수량
+        #[getset(get = "pub")]
+        tot_ccld_qty: String,
+        /// 평균가(체결평균가)
+        #[getset(get = "pub")]
+        avg_prvs: String,
+        /// 취소여부
+        #[getset(get = "pub")]
+        cncl_yn: String,
+        /// 총체결금액
+        #[getset(get = "pub")]
+        tot_ccld_amt: String,
+        /// 대출일자
+        #[getset(get = "pub")]
+        loan_dt: String,
+        /// 주문구분코드
+        #[getset(get = "pub")]
+        ord_dvsn_cd: String,
+        /// 취소확인수량
+        #[getset(get = "pub")]
+        cncl_cfrm_qty: String,
+        /// 잔여수량
+        #[getset(get = "pub")]
+        rmn_qty: String,
+        /// 거부수량
+        #[getset(get = "pub")]
+        rjct_qty: String,
+        /// 체결조건명
+        #[getset(get = "pub")]
+        ccld_cndt_name: String,
+        /// 통보시각
+        #[getset(get = "pub")]
+        infm_tmd: String,
+        /// 연락전화번호
+        #[getset(get = "pub")]
+        ctac_tlno: String,
+        /// 상품유형코드
+        #[getset(get = "pub")]
+        prdt_type_cd: String,
+        /// 거래소구분코드
+        #[getset(get = "pub")]
+        excg_dvsn_cd: String,
+    }
+
+    /// 주식일별주문체결조회 - 합계
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct InquireDailyCcldSummary {
+        /// 총주문수량
+        #[getset(get = "pub")]
+        tot_ord_qty: String,
+        /// 총체결수량
+        #[getset(get = "pub")]
+        tot_ccld_qty: String,
+        /// 총체결금액
+        #[getset(get = "pub")]
+        tot_ccld_amt: String,
+        /// 추정제비용합계
+        #[getset(get = "pub")]
+        prsm_tlex_smtl: String,
+        /// 매입평균가격
+        #[getset(get = "pub")]
+        pchs_avg_pric: String,
+    }
+
+    /// 주식예약주문조회
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquireReserveOrder {
+        /// 예약주문순번
+        #[getset(get = "pub")]
+        rsvn_ord_seq: String,
+        /// 예약주문주문일자
+        #[getset(get = "pub")]
+        rsvn_ord_ord_dt: String,
+        /// 예약주문접수일자
+        #[getset(get = "pub")]
+        rsvn_ord_rcit_dt: String,
+        /// 상품번호
+        #[getset(get = "pub")]
+        pdno: String,
+        /// 주문구분코드
+        #[getset(get = "pub")]
+        ord_dvsn_cd: String,
+        /// 주문예약수량
+        #[getset(get = "pub")]
+        ord_rsvn_qty: String,
+        /// 총체결수량
+        #[getset(get = "pub")]
+        tot_ccld_qty: String,
+        /// 취소주문일자
+        #[getset(get = "pub")]
+        cncl_ord_dt: String,
+        /// 주문시각
+        #[getset(get = "pub")]
+        ord_tmd: String,
+        /// 연락전화번호
+        #[getset(get = "pub")]
+        ctac_tlno: String,
+        /// 거부사유2
+        #[getset(get = "pub")]
+        rjct_rson2: String,
+        /// 주문번호
+        #[getset(get = "pub")]
+        odno: String,
+        /// 예약주문접수시각
+        #[getset(get = "pub")]
+        rsvn_ord_rcit_tmd: String,
+        /// 한글종목명
+        #[getset(get = "pub")]
+        kor_item_name: String,
+        /// 매도매수구분코드(01: 매도, 02: 매수)
+        #[getset(get = "pub")]
+        sll_buy_dvsn_cd: String,
+        /// 주문예약단가
+        #[getset(get = "pub")]
+        ord_rsvn_unpr: String,
+        /// 총체결금액
+        #[getset(get = "pub")]
+        tot_ccld_amt: String,
+        /// 대출일자
+        #[getset(get = "pub")]
+        loan_dt: String,
+        /// 취소접수시각
+        #[getset(get = "pub")]
+        cncl_rcit_tmd: String,
+        /// 처리결과
+        #[getset(get = "pub")]
+        prcs_rslt: String,
+        /// 주문구분명
+        #[getset(get = "pub")]
+        ord_dvsn_name: String,
+        /// 단말매체종류코드
+        #[getset(get = "pub")]
+        tmnl_mdia_kind_cd: String,
+        /// 예약종료일자
+        #[getset(get = "pub")]
+        rsvn_end_dt: String,
+        /// 주문채번지점번호(예약주문정정취소의 예약주문조직번호)
+        #[serde(default)]
+        #[getset(get = "pub")]
+        ord_gno_brno: String,
+    }
+
+    /// 주식잔고조회_실현손익 - 계좌 요약
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct InquireBalanceRlzPlSummary {
+        /// 주식잔고조회와 동일한 계좌 요약
+        #[serde(flatten)]
+        #[getset(get = "pub")]
+        summary: InquireBalanceSummary,
+        /// 실현손익
+        #[getset(get = "pub")]
+        rlzt_pfls: String,
+        /// 실현수익율
+        #[getset(get = "pub")]
+        rlzt_erng_rt: String,
+        /// 실평가손익
+        #[getset(get = "pub")]
+        real_evlu_pfls: String,
+        /// 실평가손익수익율
+        #[getset(get = "pub")]
+        real_evlu_pfls_erng_rt: String,
+    }
+
+    /// 기간별손익일별합산조회 - 일별 손익
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct PeriodProfit {
+        /// 매매일자(YYYYMMDD)
+        #[getset(get = "pub")]
+        trad_dt: String,
+        /// 매수금액
+        #[getset(get = "pub")]
+        buy_amt: String,
+        /// 매도금액
+        #[getset(get = "pub")]
+        sll_amt: String,
+        /// 실현손익
+        #[getset(get = "pub")]
+        rlzt_pfls: String,
+        /// 수수료
+        #[getset(get = "pub")]
+        fee: String,
+        /// 대출이자
+        #[getset(get = "pub")]
+        loan_int: String,
+        /// 제세금
+        #[getset(get = "pub")]
+        tl_tax: String,
+        /// 손익률
+        #[getset(get = "pub")]
+        pfls_rt: String,
+        /// 매도수량
+        #[getset(get = "pub")]
+        sll_qty1: String,
+        /// 매수수량
+        #[getset(get = "pub")]
+        buy_qty1: String,
+    }
+
+    /// 기간별매매손익현황조회 - 일별 종목별 매매손익
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct PeriodTradeProfit {
+        /// 매매일자(YYYYMMDD)
+        #[getset(get = "pub")]
+        trad_dt: String,
+        /// 상품번호
+        #[getset(get = "pub")]
+        pdno: String,
+        /// 상품명
+        #[getset(get = "pub")]
+        prdt_name: String,
+        /// 매매구분명
+        #[getset(get = "pub")]
+        trad_dvsn_name: String,
+        /// 대출일자
+        #[getset(get = "pub")]
+        loan_dt: String,
+        /// 보유수량
+        #[getset(get = "pub")]
+        hldg_qty: String,
+        /// 매입단가
+        #[getset(get = "pub")]
+        pchs_unpr: String,
+        /// 매수수량
+        #[getset(get = "pub")]
+        buy_qty: String,
+        /// 매수금액
+        #[getset(get = "pub")]
+        buy_amt: String,
+        /// 매도가격
+        #[getset(get = "pub")]
+        sll_pric: String,
+        /// 매도수량
+        #[getset(get = "pub")]
+        sll_qty: String,
+        /// 매도금액
+        #[getset(get = "pub")]
+        sll_amt: String,
+        /// 실현손익
+        #[getset(get = "pub")]
+        rlzt_pfls: String,
+        /// 손익률
+        #[getset(get = "pub")]
+        pfls_rt: String,
+        /// 수수료
+        #[getset(get = "pub")]
+        fee: String,
+        /// 제세금
+        #[getset(get = "pub")]
+        tl_tax: String,
+        /// 대출이자
+        #[getset(get = "pub")]
+        loan_int: String,
+    }
+
+    /// 기간별손익일별합산조회, 기간별매매손익현황조회 - 기간 합계
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct PeriodProfitSummary {
+        /// 매도수량합계
+        #[getset(get = "pub")]
+        sll_qty_smtl: String,
+        /// 매도거래금액합계
+        #[getset(get = "pub")]
+        sll_tr_amt_smtl: String,
+        /// 매도수수료합계
+        #[getset(get = "pub")]
+        sll_fee_smtl: String,
+        /// 매도제세금합계
+        #[getset(get = "pub")]
+        sll_tltx_smtl: String,
+        /// 매도정산금액합계
+        #[getset(get = "pub")]
+        sll_excc_amt_smtl: String,
+        /// 매수수량합계
+        #[getset(get = "pub")]
+        #[serde(alias = "buyqty_smtl")]
+        buy_qty_smtl: String,
+        /// 매수거래금액합계
+        #[getset(get = "pub")]
+        buy_tr_amt_smtl: String,
+        /// 매수수수료합계
+        #[getset(get = "pub")]
+        buy_fee_smtl: String,
+        /// 매수제세금합계
+        #[getset(get = "pub")]
+        buy_tax_smtl: String,
+        /// 매수정산금액합계
+        #[getset(get = "pub")]
+        buy_excc_amt_smtl: String,
+        /// 총수량
+        #[getset(get = "pub")]
+        tot_qty: String,
+        /// 총거래금액
+        #[getset(get = "pub")]
+        tot_tr_amt: String,
+        /// 총수수료
+        #[getset(get = "pub")]
+        tot_fee: String,
+        /// 총제세금
+        #[getset(get = "pub")]
+        tot_tltx: String,
+        /// 총정산금액
+        #[getset(get = "pub")]
+        tot_excc_amt: String,
+        /// 총실현손익
+        #[getset(get = "pub")]
+        tot_rlzt_pfls: String,
+        /// 대출이자
+        #[getset(get = "pub")]
+        loan_int: String,
+        /// 총수익률
+        #[getset(get = "pub")]
+        tot_pftrt: String,
+    }
+
+    /// 투자계좌자산현황조회 - 자산구분별
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct AccountAsset {
+        /// 매입금액
+        #[getset(get = "pub")]
+        pchs_amt: String,
+        /// 평가금액
+        #[getset(get = "pub")]
+        evlu_amt: String,
+        /// 평가손익금액
+        #[getset(get = "pub")]
+        evlu_pfls_amt: String,
+        /// 신용대출금액
+        #[getset(get = "pub")]
+        crdt_lnd_amt: String,
+        /// 실제순자산금액
+        #[getset(get = "pub")]
+        real_nass_amt: String,
+        /// 전체비중율
+        #[getset(get = "pub")]
+        whol_weit_rt: String,
+    }
+
+    /// 투자계좌자산현황조회 - 계좌 합계
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct AccountAssetSummary {
+        /// 매입금액합계
+        #[getset(get = "pub")]
+        pchs_amt_smtl: String,
+        /// 순자산총금액
+        #[getset(get = "pub")]
+        nass_tot_amt: String,
+        /// 대출금액합계
+        #[getset(get = "pub")]
+        loan_amt_smtl: String,
+        /// 평가손익금액합계
+        #[getset(get = "pub")]
+        evlu_pfls_amt_smtl: String,
+        /// 평가금액합계
+        #[getset(get = "pub")]
+        evlu_amt_smtl: String,
+        /// 총자산금액
+        #[getset(get = "pub")]
+        tot_asst_amt: String,
+        /// 총대출금액총융자대출금액
+        #[getset(get = "pub")]
+        tot_lnda_tot_ulst_lnda: String,
+        /// CMA자동대출금액
+        #[getset(get = "pub")]
+        cma_auto_loan_amt: String,
+        /// 총담보대출금액
+        #[getset(get = "pub")]
+        tot_mgln_amt: String,
+        /// 대주평가금액
+        #[getset(get = "pub")]
+        stln_evlu_amt: String,
+        /// 신용융자금액
+        #[getset(get = "pub")]
+        crdt_fncg_amt: String,
+        /// OCL_APL대출금액
+        #[getset(get = "pub")]
+        ocl_apl_loan_amt: String,
+        /// 질권설정금액
+        #[getset(get = "pub")]
+        pldg_stup_amt: String,
+        /// 외화평가총액
+        #[getset(get = "pub")]
+        frcr_evlu_tota: String,
+        /// 총예수금액
+        #[getset(get = "pub")]
+        tot_dncl_amt: String,
+        /// CMA평가금액
+        #[getset(get = "pub")]
+        cma_evlu_amt: String,
+        /// 예수금액
+        #[getset(get = "pub")]
+        dncl_amt: String,
+        /// 총대용금액
+        #[getset(get = "pub")]
+        tot_sbst_amt: String,
+        /// 당일미수금액
+        #[getset(get = "pub")]
+        thdt_rcvb_amt: String,
+        /// 해외주식평가금액1
+        #[getset(get = "pub")]
+        ovrs_stck_evlu_amt1: String,
+        /// 해외채권평가금액
+        #[getset(get = "pub")]
+        ovrs_bond_evlu_amt: String,
+        /// MMFCMA담보대출금액
+        #[getset(get = "pub")]
+        mmf_cma_mgge_loan_amt: String,
+        /// 청약예수금액
+        #[getset(get = "pub")]
+        sbsc_dncl_amt: String,
+        /// 공모청약자금대출사용금액
+        #[getset(get = "pub")]
+        pbst_sbsc_fnds_loan_use_amt: String,
+        /// 기업신용공여대출금액
+        #[getset(get = "pub")]
+        etpr_crdt_grnt_loan_amt: String,
     }
 }
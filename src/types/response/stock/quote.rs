@@ -1,11 +1,29 @@
+use crate::types::session::Session;
+use crate::types::stream::stock::{exec, ordb};
+use crate::types::{
+    DealClassCode, ExecClass, MarketOperationClassCode, MarketTerminationClassCode, Time,
+    TimeClassCode, VsPriceSign,
+};
+use crate::Error;
 use getset::Getters;
 use serde::Deserialize;
+use std::str::FromStr;
 
+pub type CurrentPriceResponse = QuoteResponse<output::CurrentPrice, (), ()>;
 pub type DailyPriceResponse = QuoteResponse<Vec<output::DailyPrice>, (), ()>;
 pub type VolumeRankResponse = QuoteResponse<Vec<output::VolumeRank>, (), ()>;
 pub type GroupListResponse = QuoteResponse<Vec<output::GroupList>, (), Vec<output::GroupList>>;
 pub type GroupItemResponse = QuoteResponse<(), output::GroupInfo, Vec<output::GroupItem>>;
 pub type BasicStockInfoResponse = QuoteResponse<output::BasicStockInfo, (), ()>;
+pub type AskingPriceExpCcnResponse =
+    QuoteResponse<(), output::AskingPrice, output::ExpectedExecution>;
+pub type TimeItemConclusionResponse =
+    QuoteResponse<(), output::TimeItemConclusionSummary, Vec<output::TimeItemConclusion>>;
+pub type CcnlResponse = QuoteResponse<Vec<output::Ccnl>, (), ()>;
+pub type MinuteChartPriceResponse =
+    QuoteResponse<(), output::MinuteChartSummary, Vec<output::MinuteChartPrice>>;
+pub type DailyItemChartPriceResponse =
+    QuoteResponse<(), output::DailyItemChartSummary, Vec<output::DailyItemChartPrice>>;
 
 #[derive(Clone, Debug, Deserialize, Getters)]
 pub struct QuoteResponse<A, B, C> {
@@ -29,6 +47,219 @@ pub struct QuoteResponse<A, B, C> {
     output2: Option<C>,
 }
 
+impl AskingPriceExpCcnResponse {
+    /// 실시간 호가(H0STASP0)와 같은 구조로 변환
+    /// 예상 체결량은 예상 거래량으로 채우고, 응답에 없는 누적 거래량은 0, 매매구분코드는 빈 값
+    pub fn ordb(&self) -> Result<ordb::Body, Error> {
+        let (book, expected) = match (&self.output1, &self.output2) {
+            (Some(book), Some(expected)) if self.rt_cd == "0" => (book, expected),
+            _ => {
+                return Err(Error::BrokenProtocol(
+                    "AskingPriceExpCcn",
+                    format!("{}: {}", self.msg_cd, self.msg1),
+                ))
+            }
+        };
+        let time = today_at(book.aspr_acpt_hour())?;
+        let time_class_code = match book.new_mkop_cls_code().get(0..1) {
+            Some("1") => TimeClassCode::PreMarketPrediction,
+            Some("3") => TimeClassCode::PostMarketPrediction,
+            Some("4") => TimeClassCode::OutMarketSinglePricePredict,
+            _ => TimeClassCode::InMarket,
+        };
+        Ok(ordb::Body {
+            shortcode: expected.stck_shrn_iscd().clone(),
+            time,
+            time_class_code,
+            ask_price: levels(book.ask_price())?,
+            bid_price: levels(book.bid_price())?,
+            ask_remained: levels(book.ask_remained())?,
+            bid_remained: levels(book.bid_remained())?,
+            total_ask_order_remained: number(book.total_askp_rsqn())?,
+            total_bid_order_remained: number(book.total_bidp_rsqn())?,
+            total_otc_ask_order_remained: number(book.ovtm_total_askp_rsqn())?,
+            total_otc_bid_order_remained: number(book.ovtm_total_bidp_rsqn())?,
+            predicted_exec_price: number(expected.antc_cnpr())?,
+            predicted_exec_quantity: number(expected.antc_vol())?,
+            predicted_volume: number(expected.antc_vol())?,
+            predicted_vs_exec: number(expected.antc_cntg_vrss())?,
+            predicted_vs_exec_sign: sign(expected.antc_cntg_vrss_sign()),
+            predicted_exec_price_rate_vs_yesterday: number(expected.antc_cntg_prdy_ctrt())?,
+            accumulative_exec_volume: 0,
+            total_ask_order_remained_diff: number(book.total_askp_rsqn_icdc())?,
+            total_bid_order_remained_diff: number(book.total_bidp_rsqn_icdc())?,
+            total_otc_ask_order_remained_diff: number(book.ovtm_total_askp_icdc())?,
+            total_otc_bid_order_remained_diff: number(book.ovtm_total_bidp_icdc())?,
+            stock_deal_class_code: DealClassCode::from(""),
+        })
+    }
+}
+
+impl TimeItemConclusionResponse {
+    /// 실시간 체결가(H0STCNT0)와 같은 구조로 변환(오래된 체결부터)
+    /// 응답에 영업일자가 없으므로 조회한 영업일자(date)를 넘김
+    pub fn execs(&self, shortcode: &str, date: &Time) -> Result<Vec<exec::Body>, Error> {
+        let rows = self.output2.as_deref().unwrap_or_default();
+        execs(
+            shortcode,
+            date,
+            rows.iter().map(output::TimeItemConclusion::tick),
+        )
+    }
+}
+
+impl CcnlResponse {
+    /// 실시간 체결가(H0STCNT0)와 같은 구조로 변환(오래된 체결부터), 누적 거래량은 0
+    /// 응답에 영업일자가 없으므로 조회한 영업일자(date)를 넘김
+    pub fn execs(&self, shortcode: &str, date: &Time) -> Result<Vec<exec::Body>, Error> {
+        let rows = self.output.as_deref().unwrap_or_default();
+        execs(shortcode, date, rows.iter().map(output::Ccnl::tick))
+    }
+}
+
+/// 체결 조회 응답 한 건
+pub(crate) struct Tick<'a> {
+    pub(crate) hour: &'a str,
+    pub(crate) price: &'a str,
+    pub(crate) sign: &'a str,
+    pub(crate) vs: &'a str,
+    pub(crate) rate: &'a str,
+    pub(crate) ask: &'a str,
+    pub(crate) bid: &'a str,
+    pub(crate) volume: &'a str,
+    pub(crate) accumulative_volume: &'a str,
+    pub(crate) power: &'a str,
+}
+
+/// 최근 체결부터 온 응답을 오래된 체결부터 실시간 체결가 구조로 변환
+/// 체결구분은 체결가가 매도호가 이상이면 매수, 매수호가 이하면 매도로 보고,
+/// 호가가 없으면 직전 체결가 대비 상승은 매수, 하락은 매도, 같으면 직전 체결구분을 따름
+/// 시간구분코드는 체결 시각의 KRX 시간대로 정하며, 응답에 없는 시가/고가/저가, 누적 거래대금 등은 0
+pub(crate) fn execs<'a>(
+    shortcode: &str,
+    date: &Time,
+    ticks: impl DoubleEndedIterator<Item = Tick<'a>>,
+) -> Result<Vec<exec::Body>, Error> {
+    let date = date.date();
+    let business_operation_date = Time::parse(&date, "%Y%m%d")?;
+    let mut result: Vec<exec::Body> = vec![];
+    for tick in ticks.rev() {
+        let exec_time = Time::parse(&format!("{}{}", date, tick.hour.trim()), "%Y%m%d%H%M%S")?;
+        let time_class_code = time_class_code(&exec_time);
+        let current_price: u32 = number(tick.price)?;
+        let ask_price: u32 = number(tick.ask)?;
+        let bid_price: u32 = number(tick.bid)?;
+        let exec_class = match result.last() {
+            _ if ask_price > 0 && current_price >= ask_price => ExecClass::Bid,
+            _ if bid_price > 0 && current_price <= bid_price => ExecClass::Ask,
+            Some(prev) if current_price > prev.current_price => ExecClass::Bid,
+            Some(prev) if current_price < prev.current_price => ExecClass::Ask,
+            Some(prev) => prev.exec_class.clone(),
+            None => ExecClass::Bid,
+        };
+        result.push(exec::Body {
+            shortcode: shortcode.to_string(),
+            exec_time: exec_time.clone(),
+            current_price,
+            price_sign_vs_yesterday: sign(tick.sign),
+            price_vs_yesterday: number(tick.vs)?,
+            price_rate_vs_yesterday: number(tick.rate)?,
+            weighted_average_price: 0.0,
+            market_price: 0,
+            market_upper_price: 0,
+            market_lower_price: 0,
+            ask_price,
+            bid_price,
+            exec_volume: number(tick.volume)?,
+            accumulative_exec_volume: number(tick.accumulative_volume)?,
+            accumulative_exec_amount: 0,
+            ask_exec_count: 0,
+            bid_exec_count: 0,
+            natural_bid_exec_count: 0,
+            volume_power: number(tick.power)?,
+            total_ask_exec_volume: 0,
+            total_bid_exec_volume: 0,
+            exec_class,
+            bid_rate: 0.0,
+            exec_volume_rate_vs_yesterday: 0.0,
+            market_price_time: exec_time.clone(),
+            vs_market_price_sign: VsPriceSign::default(),
+            vs_market_price: 0,
+            upper_price_time: exec_time.clone(),
+            vs_upper_price_sign: VsPriceSign::default(),
+            vs_upper_price: 0,
+            lower_price_time: exec_time,
+            vs_lower_price_sign: VsPriceSign::default(),
+            vs_lower_price: 0,
+            business_operation_date: business_operation_date.clone(),
+            new_market_operation_class_code: MarketOperationClassCode::from("20"),
+            trade_suspended: false,
+            ask_order_remained: 0,
+            bid_order_remained: 0,
+            total_ask_order_remained: 0,
+            total_bid_order_remained: 0,
+            turnover_ratio: 0.0,
+            yesterday_symmetric_time_accumulate_volume: 0,
+            yesterday_symmetric_time_accumulate_volume_rate: 0.0,
+            time_class_code,
+            market_termination_class_code: MarketTerminationClassCode::from(""),
+            vi_standard_price: 0,
+        });
+    }
+    Ok(result)
+}
+
+/// 체결 시각의 시간구분코드(Session::from_exec로 같은 시간대를 돌려받도록)
+fn time_class_code(exec_time: &Time) -> TimeClassCode {
+    match Session::at(exec_time) {
+        Session::OpeningAuction => TimeClassCode::PreMarketPrediction,
+        Session::ClosingAuction => TimeClassCode::PostMarketPrediction,
+        Session::AfterHoursSinglePrice => TimeClassCode::OutMarketSinglePricePredict,
+        _ => TimeClassCode::InMarket,
+    }
+}
+
+/// 당일(한국시간) HHMMSS
+fn today_at(hour: &str) -> Result<Time, Error> {
+    let business_operation_date = chrono::Utc::now()
+        .with_timezone(&chrono_tz::Asia::Seoul)
+        .format("%Y%m%d")
+        .to_string();
+    Time::parse(&(business_operation_date + hour.trim()), "%Y%m%d%H%M%S")
+}
+
+/// 대비 부호(빈 값 등은 보합)
+fn sign(s: &str) -> VsPriceSign {
+    match s.trim() {
+        sign @ ("1" | "2" | "3" | "4" | "5") => VsPriceSign::from(sign),
+        _ => VsPriceSign::default(),
+    }
+}
+
+/// 빈 값은 0
+pub(crate) fn number<T>(s: &str) -> Result<T, Error>
+where
+    T: FromStr + Default,
+    Error: From<T::Err>,
+{
+    match s.trim() {
+        "" => Ok(T::default()),
+        s => Ok(s.parse()?),
+    }
+}
+
+fn levels<T>(values: [&String; 10]) -> Result<[T; 10], Error>
+where
+    T: FromStr + Default + Copy,
+    Error: From<T::Err>,
+{
+    let mut result = [T::default(); 10];
+    for (value, s) in result.iter_mut().zip(values) {
+        *value = number(s)?;
+    }
+    Ok(result)
+}
+
 pub mod output {
     use crate::types::{
         ExCode, ExchangeCode, MarketId, SecurityGroupId, StockKindCode, VsPriceSign,
@@ -36,6 +267,255 @@ pub mod output {
     use getset::Getters;
     use serde::Deserialize;
 
+    /// 주식현재가 시세(모의투자 등 응답에 없는 필드는 빈 값)
+    #[derive(Clone, Debug, Default, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct CurrentPrice {
+        /// 종목 상태 구분 코드(51: 관리종목, 52: 투자위험, 53: 투자경고, 54: 투자주의, 55: 신용가능, 57: 증거금 100%, 58: 거래정지, 59: 단기과열)
+        #[getset(get = "pub")]
+        iscd_stat_cls_code: String,
+        /// 증거금 비율
+        #[getset(get = "pub")]
+        marg_rate: String,
+        /// 대표 시장 한글 명
+        #[getset(get = "pub")]
+        rprs_mrkt_kor_name: String,
+        /// 신 고가 저가 구분 코드
+        #[getset(get = "pub")]
+        new_hgpr_lwpr_cls_code: String,
+        /// 업종 한글 종목명
+        #[getset(get = "pub")]
+        bstp_kor_isnm: String,
+        /// 임시 정지 여부
+        #[getset(get = "pub")]
+        temp_stop_yn: String,
+        /// 시가 범위 연장 여부
+        #[getset(get = "pub")]
+        oprc_rang_cont_yn: String,
+        /// 종가 범위 연장 여부
+        #[getset(get = "pub")]
+        clpr_rang_cont_yn: String,
+        /// 신용 가능 여부
+        #[getset(get = "pub")]
+        crdt_able_yn: String,
+        /// 보증금 비율 구분 코드
+        #[getset(get = "pub")]
+        grmn_rate_cls_code: String,
+        /// ELW 발행 여부
+        #[getset(get = "pub")]
+        elw_pblc_yn: String,
+        /// 주식 현재가
+        #[getset(get = "pub")]
+        stck_prpr: String,
+        /// 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss: String,
+        /// 전일 대비 부호
+        #[getset(get = "pub")]
+        prdy_vrss_sign: VsPriceSign,
+        /// 전일 대비율
+        #[getset(get = "pub")]
+        prdy_ctrt: String,
+        /// 누적 거래 대금
+        #[getset(get = "pub")]
+        acml_tr_pbmn: String,
+        /// 누적 거래량
+        #[getset(get = "pub")]
+        acml_vol: String,
+        /// 전일 대비 거래량 비율
+        #[getset(get = "pub")]
+        prdy_vrss_vol_rate: String,
+        /// 주식 시가
+        #[getset(get = "pub")]
+        stck_oprc: String,
+        /// 주식 최고가
+        #[getset(get = "pub")]
+        stck_hgpr: String,
+        /// 주식 최저가
+        #[getset(get = "pub")]
+        stck_lwpr: String,
+        /// 주식 상한가
+        #[getset(get = "pub")]
+        stck_mxpr: String,
+        /// 주식 하한가
+        #[getset(get = "pub")]
+        stck_llam: String,
+        /// 주식 기준가
+        #[getset(get = "pub")]
+        stck_sdpr: String,
+        /// 가중 평균 주식 가격
+        #[getset(get = "pub")]
+        wghn_avrg_stck_prc: String,
+        /// HTS 외국인 소진율
+        #[getset(get = "pub")]
+        hts_frgn_ehrt: String,
+        /// 외국인 순매수 수량
+        #[getset(get = "pub")]
+        frgn_ntby_qty: String,
+        /// 프로그램매매 순매수 수량
+        #[getset(get = "pub")]
+        pgtr_ntby_qty: String,
+        /// 피벗 2차 디저항 가격
+        #[getset(get = "pub")]
+        pvt_scnd_dmrs_prc: String,
+        /// 피벗 1차 디저항 가격
+        #[getset(get = "pub")]
+        pvt_frst_dmrs_prc: String,
+        /// 피벗 포인트 값
+        #[getset(get = "pub")]
+        pvt_pont_val: String,
+        /// 피벗 1차 디지지 가격
+        #[getset(get = "pub")]
+        pvt_frst_dmsp_prc: String,
+        /// 피벗 2차 디지지 가격
+        #[getset(get = "pub")]
+        pvt_scnd_dmsp_prc: String,
+        /// 디저항 값
+        #[getset(get = "pub")]
+        dmrs_val: String,
+        /// 디지지 값
+        #[getset(get = "pub")]
+        dmsp_val: String,
+        /// 자본금
+        #[getset(get = "pub")]
+        cpfn: String,
+        /// 제한 폭 가격
+        #[getset(get = "pub")]
+        rstc_wdth_prc: String,
+        /// 주식 액면가
+        #[getset(get = "pub")]
+        stck_fcam: String,
+        /// 주식 대용가
+        #[getset(get = "pub")]
+        stck_sspr: String,
+        /// 호가단위
+        #[getset(get = "pub")]
+        aspr_unit: String,
+        /// HTS 매매 수량 단위 값
+        #[getset(get = "pub")]
+        hts_deal_qty_unit_val: String,
+        /// 상장 주수
+        #[getset(get = "pub")]
+        lstn_stcn: String,
+        /// HTS 시가총액(억원)
+        #[getset(get = "pub")]
+        hts_avls: String,
+        /// PER
+        #[getset(get = "pub")]
+        per: String,
+        /// PBR
+        #[getset(get = "pub")]
+        pbr: String,
+        /// 결산 월
+        #[getset(get = "pub")]
+        stac_month: String,
+        /// 거래량 회전율
+        #[getset(get = "pub")]
+        vol_tnrt: String,
+        /// EPS
+        #[getset(get = "pub")]
+        eps: String,
+        /// BPS
+        #[getset(get = "pub")]
+        bps: String,
+        /// 250일 최고가
+        #[getset(get = "pub")]
+        d250_hgpr: String,
+        /// 250일 최고가 일자
+        #[getset(get = "pub")]
+        d250_hgpr_date: String,
+        /// 250일 최고가 대비 현재가 비율
+        #[getset(get = "pub")]
+        d250_hgpr_vrss_prpr_rate: String,
+        /// 250일 최저가
+        #[getset(get = "pub")]
+        d250_lwpr: String,
+        /// 250일 최저가 일자
+        #[getset(get = "pub")]
+        d250_lwpr_date: String,
+        /// 250일 최저가 대비 현재가 비율
+        #[getset(get = "pub")]
+        d250_lwpr_vrss_prpr_rate: String,
+        /// 주식 연중 최고가
+        #[getset(get = "pub")]
+        stck_dryy_hgpr: String,
+        /// 연중 최고가 대비 현재가 비율
+        #[getset(get = "pub")]
+        dryy_hgpr_vrss_prpr_rate: String,
+        /// 연중 최고가 일자
+        #[getset(get = "pub")]
+        dryy_hgpr_date: String,
+        /// 주식 연중 최저가
+        #[getset(get = "pub")]
+        stck_dryy_lwpr: String,
+        /// 연중 최저가 대비 현재가 비율
+        #[getset(get = "pub")]
+        dryy_lwpr_vrss_prpr_rate: String,
+        /// 연중 최저가 일자
+        #[getset(get = "pub")]
+        dryy_lwpr_date: String,
+        /// 52주 최고가
+        #[getset(get = "pub")]
+        w52_hgpr: String,
+        /// 52주 최고가 대비 현재가 대비
+        #[getset(get = "pub")]
+        w52_hgpr_vrss_prpr_ctrt: String,
+        /// 52주 최고가 일자
+        #[getset(get = "pub")]
+        w52_hgpr_date: String,
+        /// 52주 최저가
+        #[getset(get = "pub")]
+        w52_lwpr: String,
+        /// 52주 최저가 대비 현재가 대비
+        #[getset(get = "pub")]
+        w52_lwpr_vrss_prpr_ctrt: String,
+        /// 52주 최저가 일자
+        #[getset(get = "pub")]
+        w52_lwpr_date: String,
+        /// 전체 융자 잔고 비율
+        #[getset(get = "pub")]
+        whol_loan_rmnd_rate: String,
+        /// 공매도가능여부
+        #[getset(get = "pub")]
+        ssts_yn: String,
+        /// 주식 단축 종목코드
+        #[getset(get = "pub")]
+        stck_shrn_iscd: String,
+        /// 액면가 통화명
+        #[getset(get = "pub")]
+        fcam_cnnm: String,
+        /// 자본금 통화명
+        #[getset(get = "pub")]
+        cpfn_cnnm: String,
+        /// 외국인 보유 수량
+        #[getset(get = "pub")]
+        frgn_hldn_qty: String,
+        /// VI적용구분코드
+        #[getset(get = "pub")]
+        vi_cls_code: String,
+        /// 시간외단일가VI적용구분코드
+        #[getset(get = "pub")]
+        ovtm_vi_cls_code: String,
+        /// 최종 공매도 체결 수량
+        #[getset(get = "pub")]
+        last_ssts_cntg_qty: String,
+        /// 투자유의여부
+        #[getset(get = "pub")]
+        invt_caful_yn: String,
+        /// 시장경고코드(00: 없음, 01: 투자주의, 02: 투자경고, 03: 투자위험)
+        #[getset(get = "pub")]
+        mrkt_warn_cls_code: String,
+        /// 단기과열여부
+        #[getset(get = "pub")]
+        short_over_yn: String,
+        /// 정리매매여부
+        #[getset(get = "pub")]
+        sltr_yn: String,
+        /// 관리종목여부
+        #[getset(get = "pub")]
+        mang_issu_cls_code: String,
+    }
+
     #[derive(Clone, Debug, Deserialize, Getters)]
     pub struct DailyPrice {
         /// 주식 영업 일자
@@ -407,4 +887,659 @@ pub mod output {
         #[getset(get = "pub")]
         trst_istt_issu_istt_cd: String,
     }
+
+    /// 주식현재가 호가(10단계)
+    #[derive(Clone, Debug, Default, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct AskingPrice {
+        /// 호가 접수 시간(HHMMSS)
+        #[getset(get = "pub")]
+        aspr_acpt_hour: String,
+        /// 매도호가1
+        #[getset(get = "pub")]
+        askp1: String,
+        /// 매도호가2
+        #[getset(get = "pub")]
+        askp2: String,
+        /// 매도호가3
+        #[getset(get = "pub")]
+        askp3: String,
+        /// 매도호가4
+        #[getset(get = "pub")]
+        askp4: String,
+        /// 매도호가5
+        #[getset(get = "pub")]
+        askp5: String,
+        /// 매도호가6
+        #[getset(get = "pub")]
+        askp6: String,
+        /// 매도호가7
+        #[getset(get = "pub")]
+        askp7: String,
+        /// 매도호가8
+        #[getset(get = "pub")]
+        askp8: String,
+        /// 매도호가9
+        #[getset(get = "pub")]
+        askp9: String,
+        /// 매도호가10
+        #[getset(get = "pub")]
+        askp10: String,
+        /// 매수호가1
+        #[getset(get = "pub")]
+        bidp1: String,
+        /// 매수호가2
+        #[getset(get = "pub")]
+        bidp2: String,
+        /// 매수호가3
+        #[getset(get = "pub")]
+        bidp3: String,
+        /// 매수호가4
+        #[getset(get = "pub")]
+        bidp4: String,
+        /// 매수호가5
+        #[getset(get = "pub")]
+        bidp5: String,
+        /// 매수호가6
+        #[getset(get = "pub")]
+        bidp6: String,
+        /// 매수호가7
+        #[getset(get = "pub")]
+        bidp7: String,
+        /// 매수호가8
+        #[getset(get = "pub")]
+        bidp8: String,
+        /// 매수호가9
+        #[getset(get = "pub")]
+        bidp9: String,
+        /// 매수호가10
+        #[getset(get = "pub")]
+        bidp10: String,
+        /// 매도호가 잔량1
+        #[getset(get = "pub")]
+        askp_rsqn1: String,
+        /// 매도호가 잔량2
+        #[getset(get = "pub")]
+        askp_rsqn2: String,
+        /// 매도호가 잔량3
+        #[getset(get = "pub")]
+        askp_rsqn3: String,
+        /// 매도호가 잔량4
+        #[getset(get = "pub")]
+        askp_rsqn4: String,
+        /// 매도호가 잔량5
+        #[getset(get = "pub")]
+        askp_rsqn5: String,
+        /// 매도호가 잔량6
+        #[getset(get = "pub")]
+        askp_rsqn6: String,
+        /// 매도호가 잔량7
+        #[getset(get = "pub")]
+        askp_rsqn7: String,
+        /// 매도호가 잔량8
+        #[getset(get = "pub")]
+        askp_rsqn8: String,
+        /// 매도호가 잔량9
+        #[getset(get = "pub")]
+        askp_rsqn9: String,
+        /// 매도호가 잔량10
+        #[getset(get = "pub")]
+        askp_rsqn10: String,
+        /// 매수호가 잔량1
+        #[getset(get = "pub")]
+        bidp_rsqn1: String,
+        /// 매수호가 잔량2
+        #[getset(get = "pub")]
+        bidp_rsqn2: String,
+        /// 매수호가 잔량3
+        #[getset(get = "pub")]
+        bidp_rsqn3: String,
+        /// 매수호가 잔량4
+        #[getset(get = "pub")]
+        bidp_rsqn4: String,
+        /// 매수호가 잔량5
+        #[getset(get = "pub")]
+        bidp_rsqn5: String,
+        /// 매수호가 잔량6
+        #[getset(get = "pub")]
+        bidp_rsqn6: String,
+        /// 매수호가 잔량7
+        #[getset(get = "pub")]
+        bidp_rsqn7: String,
+        /// 매수호가 잔량8
+        #[getset(get = "pub")]
+        bidp_rsqn8: String,
+        /// 매수호가 잔량9
+        #[getset(get = "pub")]
+        bidp_rsqn9: String,
+        /// 매수호가 잔량10
+        #[getset(get = "pub")]
+        bidp_rsqn10: String,
+        /// 매도호가 잔량 증감1
+        #[getset(get = "pub")]
+        askp_rsqn_icdc1: String,
+        /// 매도호가 잔량 증감2
+        #[getset(get = "pub")]
+        askp_rsqn_icdc2: String,
+        /// 매도호가 잔량 증감3
+        #[getset(get = "pub")]
+        askp_rsqn_icdc3: String,
+        /// 매도호가 잔량 증감4
+        #[getset(get = "pub")]
+        askp_rsqn_icdc4: String,
+        /// 매도호가 잔량 증감5
+        #[getset(get = "pub")]
+        askp_rsqn_icdc5: String,
+        /// 매도호가 잔량 증감6
+        #[getset(get = "pub")]
+        askp_rsqn_icdc6: String,
+        /// 매도호가 잔량 증감7
+        #[getset(get = "pub")]
+        askp_rsqn_icdc7: String,
+        /// 매도호가 잔량 증감8
+        #[getset(get = "pub")]
+        askp_rsqn_icdc8: String,
+        /// 매도호가 잔량 증감9
+        #[getset(get = "pub")]
+        askp_rsqn_icdc9: String,
+        /// 매도호가 잔량 증감10
+        #[getset(get = "pub")]
+        askp_rsqn_icdc10: String,
+        /// 매수호가 잔량 증감1
+        #[getset(get = "pub")]
+        bidp_rsqn_icdc1: String,
+        /// 매수호가 잔량 증감2
+        #[getset(get = "pub")]
+        bidp_rsqn_icdc2: String,
+        /// 매수호가 잔량 증감3
+        #[getset(get = "pub")]
+        bidp_rsqn_icdc3: String,
+        /// 매수호가 잔량 증감4
+        #[getset(get = "pub")]
+        bidp_rsqn_icdc4: String,
+        /// 매수호가 잔량 증감5
+        #[getset(get = "pub")]
+        bidp_rsqn_icdc5: String,
+        /// 매수호가 잔량 증감6
+        #[getset(get = "pub")]
+        bidp_rsqn_icdc6: String,
+        /// 매수호가 잔량 증감7
+        #[getset(get = "pub")]
+        bidp_rsqn_icdc7: String,
+        /// 매수호가 잔량 증감8
+        #[getset(get = "pub")]
+        bidp_rsqn_icdc8: String,
+        /// 매수호가 잔량 증감9
+        #[getset(get = "pub")]
+        bidp_rsqn_icdc9: String,
+        /// 매수호가 잔량 증감10
+        #[getset(get = "pub")]
+        bidp_rsqn_icdc10: String,
+        /// 총 매도호가 잔량
+        #[getset(get = "pub")]
+        total_askp_rsqn: String,
+        /// 총 매수호가 잔량
+        #[getset(get = "pub")]
+        total_bidp_rsqn: String,
+        /// 총 매도호가 잔량 증감
+        #[getset(get = "pub")]
+        total_askp_rsqn_icdc: String,
+        /// 총 매수호가 잔량 증감
+        #[getset(get = "pub")]
+        total_bidp_rsqn_icdc: String,
+        /// 시간외 총 매도호가 증감
+        #[getset(get = "pub")]
+        ovtm_total_askp_icdc: String,
+        /// 시간외 총 매수호가 증감
+        #[getset(get = "pub")]
+        ovtm_total_bidp_icdc: String,
+        /// 시간외 총 매도호가 잔량
+        #[getset(get = "pub")]
+        ovtm_total_askp_rsqn: String,
+        /// 시간외 총 매수호가 잔량
+        #[getset(get = "pub")]
+        ovtm_total_bidp_rsqn: String,
+        /// 순매수 호가 잔량
+        #[getset(get = "pub")]
+        ntby_aspr_rsqn: String,
+        /// 신 장운영 구분 코드
+        #[getset(get = "pub")]
+        new_mkop_cls_code: String,
+    }
+
+    impl AskingPrice {
+        /// 매도호가1~10
+        pub fn ask_price(&self) -> [&String; 10] {
+            [
+                &self.askp1,
+                &self.askp2,
+                &self.askp3,
+                &self.askp4,
+                &self.askp5,
+                &self.askp6,
+                &self.askp7,
+                &self.askp8,
+                &self.askp9,
+                &self.askp10,
+            ]
+        }
+
+        /// 매수호가1~10
+        pub fn bid_price(&self) -> [&String; 10] {
+            [
+                &self.bidp1,
+                &self.bidp2,
+                &self.bidp3,
+                &self.bidp4,
+                &self.bidp5,
+                &self.bidp6,
+                &self.bidp7,
+                &self.bidp8,
+                &self.bidp9,
+                &self.bidp10,
+            ]
+        }
+
+        /// 매도호가 잔량1~10
+        pub fn ask_remained(&self) -> [&String; 10] {
+            [
+                &self.askp_rsqn1,
+                &self.askp_rsqn2,
+                &self.askp_rsqn3,
+                &self.askp_rsqn4,
+                &self.askp_rsqn5,
+                &self.askp_rsqn6,
+                &self.askp_rsqn7,
+                &self.askp_rsqn8,
+                &self.askp_rsqn9,
+                &self.askp_rsqn10,
+            ]
+        }
+
+        /// 매수호가 잔량1~10
+        pub fn bid_remained(&self) -> [&String; 10] {
+            [
+                &self.bidp_rsqn1,
+                &self.bidp_rsqn2,
+                &self.bidp_rsqn3,
+                &self.bidp_rsqn4,
+                &self.bidp_rsqn5,
+                &self.bidp_rsqn6,
+                &self.bidp_rsqn7,
+                &self.bidp_rsqn8,
+                &self.bidp_rsqn9,
+                &self.bidp_rsqn10,
+            ]
+        }
+    }
+
+    /// 주식현재가 예상체결
+    #[derive(Clone, Debug, Default, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct ExpectedExecution {
+        /// 예상 장운영 구분 코드
+        #[getset(get = "pub")]
+        antc_mkop_cls_code: String,
+        /// 주식 현재가
+        #[getset(get = "pub")]
+        stck_prpr: String,
+        /// 주식 시가
+        #[getset(get = "pub")]
+        stck_oprc: String,
+        /// 주식 최고가
+        #[getset(get = "pub")]
+        stck_hgpr: String,
+        /// 주식 최저가
+        #[getset(get = "pub")]
+        stck_lwpr: String,
+        /// 주식 기준가
+        #[getset(get = "pub")]
+        stck_sdpr: String,
+        /// 예상 체결가
+        #[getset(get = "pub")]
+        antc_cnpr: String,
+        /// 예상 체결 대비 부호
+        #[getset(get = "pub")]
+        antc_cntg_vrss_sign: String,
+        /// 예상 체결 대비
+        #[getset(get = "pub")]
+        antc_cntg_vrss: String,
+        /// 예상 체결 전일 대비율
+        #[getset(get = "pub")]
+        antc_cntg_prdy_ctrt: String,
+        /// 예상 거래량
+        #[getset(get = "pub")]
+        antc_vol: String,
+        /// 주식 단축 종목코드
+        #[getset(get = "pub")]
+        stck_shrn_iscd: String,
+        /// VI적용구분코드
+        #[getset(get = "pub")]
+        vi_cls_code: String,
+    }
+
+    /// 주식현재가 당일시간대별체결 현재가
+    #[derive(Clone, Debug, Default, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct TimeItemConclusionSummary {
+        /// 주식 현재가
+        #[getset(get = "pub")]
+        stck_prpr: String,
+        /// 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss: String,
+        /// 전일 대비 부호
+        #[getset(get = "pub")]
+        prdy_vrss_sign: String,
+        /// 전일 대비율
+        #[getset(get = "pub")]
+        prdy_ctrt: String,
+        /// 누적 거래량
+        #[getset(get = "pub")]
+        acml_vol: String,
+        /// 전일 거래량
+        #[getset(get = "pub")]
+        prdy_vol: String,
+        /// 대표 시장 한글 명
+        #[getset(get = "pub")]
+        rprs_mrkt_kor_name: String,
+    }
+
+    /// 주식현재가 당일시간대별체결
+    #[derive(Clone, Debug, Default, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct TimeItemConclusion {
+        /// 주식 체결 시간(HHMMSS)
+        #[getset(get = "pub")]
+        stck_cntg_hour: String,
+        /// 주식 체결가
+        #[getset(get = "pub")]
+        stck_pbpr: String,
+        /// 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss: String,
+        /// 전일 대비 부호
+        #[getset(get = "pub")]
+        prdy_vrss_sign: String,
+        /// 전일 대비율
+        #[getset(get = "pub")]
+        prdy_ctrt: String,
+        /// 매도호가
+        #[getset(get = "pub")]
+        askp: String,
+        /// 매수호가
+        #[getset(get = "pub")]
+        bidp: String,
+        /// 당일 체결강도
+        #[getset(get = "pub")]
+        tday_rltv: String,
+        /// 누적 거래량
+        #[getset(get = "pub")]
+        acml_vol: String,
+        /// 체결량
+        #[getset(get = "pub")]
+        cnqn: String,
+    }
+
+    impl TimeItemConclusion {
+        pub(crate) fn tick(&self) -> super::Tick<'_> {
+            super::Tick {
+                hour: &self.stck_cntg_hour,
+                price: &self.stck_pbpr,
+                sign: &self.prdy_vrss_sign,
+                vs: &self.prdy_vrss,
+                rate: &self.prdy_ctrt,
+                ask: &self.askp,
+                bid: &self.bidp,
+                volume: &self.cnqn,
+                accumulative_volume: &self.acml_vol,
+                power: &self.tday_rltv,
+            }
+        }
+    }
+
+    /// 주식현재가 체결(최근 30건)
+    #[derive(Clone, Debug, Default, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct Ccnl {
+        /// 주식 체결 시간(HHMMSS)
+        #[getset(get = "pub")]
+        stck_cntg_hour: String,
+        /// 주식 현재가
+        #[getset(get = "pub")]
+        stck_prpr: String,
+        /// 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss: String,
+        /// 전일 대비 부호
+        #[getset(get = "pub")]
+        prdy_vrss_sign: String,
+        /// 체결 거래량
+        #[getset(get = "pub")]
+        cntg_vol: String,
+        /// 당일 체결강도
+        #[getset(get = "pub")]
+        tday_rltv: String,
+        /// 전일 대비율
+        #[getset(get = "pub")]
+        prdy_ctrt: String,
+    }
+
+    impl Ccnl {
+        pub(crate) fn tick(&self) -> super::Tick<'_> {
+            super::Tick {
+                hour: &self.stck_cntg_hour,
+                price: &self.stck_prpr,
+                sign: &self.prdy_vrss_sign,
+                vs: &self.prdy_vrss,
+                rate: &self.prdy_ctrt,
+                ask: "",
+                bid: "",
+                volume: &self.cntg_vol,
+                accumulative_volume: "",
+                power: &self.tday_rltv,
+            }
+        }
+    }
+
+    /// 주식 분봉조회 현재가
+    #[derive(Clone, Debug, Default, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct MinuteChartSummary {
+        /// 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss: String,
+        /// 전일 대비 부호
+        #[getset(get = "pub")]
+        prdy_vrss_sign: String,
+        /// 전일 대비율
+        #[getset(get = "pub")]
+        prdy_ctrt: String,
+        /// 주식 전일 종가
+        #[getset(get = "pub")]
+        stck_prdy_clpr: String,
+        /// 누적 거래량
+        #[getset(get = "pub")]
+        acml_vol: String,
+        /// 누적 거래 대금
+        #[getset(get = "pub")]
+        acml_tr_pbmn: String,
+        /// HTS 한글 종목명
+        #[getset(get = "pub")]
+        hts_kor_isnm: String,
+        /// 주식 현재가
+        #[getset(get = "pub")]
+        stck_prpr: String,
+    }
+
+    /// 주식 분봉(1분)
+    #[derive(Clone, Debug, Default, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct MinuteChartPrice {
+        /// 주식 영업 일자(YYYYMMDD)
+        #[getset(get = "pub")]
+        stck_bsop_date: String,
+        /// 주식 체결 시간(HHMMSS)
+        #[getset(get = "pub")]
+        stck_cntg_hour: String,
+        /// 주식 현재가(종가)
+        #[getset(get = "pub")]
+        stck_prpr: String,
+        /// 주식 시가
+        #[getset(get = "pub")]
+        stck_oprc: String,
+        /// 주식 최고가
+        #[getset(get = "pub")]
+        stck_hgpr: String,
+        /// 주식 최저가
+        #[getset(get = "pub")]
+        stck_lwpr: String,
+        /// 체결 거래량
+        #[getset(get = "pub")]
+        cntg_vol: String,
+        /// 누적 거래 대금
+        #[getset(get = "pub")]
+        acml_tr_pbmn: String,
+    }
+
+    /// 국내주식기간별시세 현재가
+    #[derive(Clone, Debug, Default, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct DailyItemChartSummary {
+        /// 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss: String,
+        /// 전일 대비 부호
+        #[getset(get = "pub")]
+        prdy_vrss_sign: String,
+        /// 전일 대비율
+        #[getset(get = "pub")]
+        prdy_ctrt: String,
+        /// 주식 전일 종가
+        #[getset(get = "pub")]
+        stck_prdy_clpr: String,
+        /// 누적 거래량
+        #[getset(get = "pub")]
+        acml_vol: String,
+        /// 누적 거래 대금
+        #[getset(get = "pub")]
+        acml_tr_pbmn: String,
+        /// HTS 한글 종목명
+        #[getset(get = "pub")]
+        hts_kor_isnm: String,
+        /// 주식 현재가
+        #[getset(get = "pub")]
+        stck_prpr: String,
+        /// 주식 단축 종목코드
+        #[getset(get = "pub")]
+        stck_shrn_iscd: String,
+        /// 전일 거래량
+        #[getset(get = "pub")]
+        prdy_vol: String,
+        /// 주식 상한가
+        #[getset(get = "pub")]
+        stck_mxpr: String,
+        /// 주식 하한가
+        #[getset(get = "pub")]
+        stck_llam: String,
+        /// 주식 시가
+        #[getset(get = "pub")]
+        stck_oprc: String,
+        /// 주식 최고가
+        #[getset(get = "pub")]
+        stck_hgpr: String,
+        /// 주식 최저가
+        #[getset(get = "pub")]
+        stck_lwpr: String,
+        /// 주식 전일 시가
+        #[getset(get = "pub")]
+        stck_prdy_oprc: String,
+        /// 주식 전일 최고가
+        #[getset(get = "pub")]
+        stck_prdy_hgpr: String,
+        /// 주식 전일 최저가
+        #[getset(get = "pub")]
+        stck_prdy_lwpr: String,
+        /// 매도호가
+        #[getset(get = "pub")]
+        askp: String,
+        /// 매수호가
+        #[getset(get = "pub")]
+        bidp: String,
+        /// 전일 대비 거래량
+        #[getset(get = "pub")]
+        prdy_vrss_vol: String,
+        /// 거래량 회전율
+        #[getset(get = "pub")]
+        vol_tnrt: String,
+        /// 주식 액면가
+        #[getset(get = "pub")]
+        stck_fcam: String,
+        /// 상장 주수
+        #[getset(get = "pub")]
+        lstn_stcn: String,
+        /// 자본금
+        #[getset(get = "pub")]
+        cpfn: String,
+        /// HTS 시가총액
+        #[getset(get = "pub")]
+        hts_avls: String,
+        /// PER
+        #[getset(get = "pub")]
+        per: String,
+        /// EPS
+        #[getset(get = "pub")]
+        eps: String,
+        /// PBR
+        #[getset(get = "pub")]
+        pbr: String,
+        /// 전체 융자 잔고 비율
+        #[getset(get = "pub")]
+        itewhol_loan_rmnd_ratem: String,
+    }
+
+    /// 국내주식기간별시세(일/주/월/년봉)
+    #[derive(Clone, Debug, Default, Deserialize, Getters)]
+    #[serde(default)]
+    pub struct DailyItemChartPrice {
+        /// 주식 영업 일자(YYYYMMDD)
+        #[getset(get = "pub")]
+        stck_bsop_date: String,
+        /// 주식 종가
+        #[getset(get = "pub")]
+        stck_clpr: String,
+        /// 주식 시가
+        #[getset(get = "pub")]
+        stck_oprc: String,
+        /// 주식 최고가
+        #[getset(get = "pub")]
+        stck_hgpr: String,
+        /// 주식 최저가
+        #[getset(get = "pub")]
+        stck_lwpr: String,
+        /// 누적 거래량
+        #[getset(get = "pub")]
+        acml_vol: String,
+        /// 누적 거래 대금
+        #[getset(get = "pub")]
+        acml_tr_pbmn: String,
+        /// 락 구분 코드
+        #[getset(get = "pub")]
+        flng_cls_code: String,
+        /// 분할 비율
+        #[getset(get = "pub")]
+        prtt_rate: String,
+        /// 분할변경여부
+        #[getset(get = "pub")]
+        mod_yn: String,
+        /// 전일 대비 부호
+        #[getset(get = "pub")]
+        prdy_vrss_sign: String,
+        /// 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss: String,
+        /// 재평가사유코드
+        #[getset(get = "pub")]
+        revl_issu_reas: String,
+    }
 }
@@ -0,0 +1,247 @@
+use crate::types::stream::stock::exec;
+use crate::types::{Exchange, OrderClass, Time, TimeClassCode};
+use crate::Error;
+use chrono::Timelike;
+
+/// 매매 시간대(한국시간, 휴장일/개장 지연은 고려하지 않음)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Session {
+    /// 장 운영 시간 외
+    Closed,
+    /// 장전 시간외 종가(08:30~08:40), 시가 동시호가 접수 병행
+    PreMarketOffHours,
+    /// 시가 동시호가(08:40~09:00)
+    OpeningAuction,
+    /// 정규장 접속매매(09:00~15:20)
+    Continuous,
+    /// 종가 동시호가(15:20~15:30)
+    ClosingAuction,
+    /// 장후 시간외 종가(15:30~16:00)
+    PostMarketOffHours,
+    /// 시간외 단일가(16:00~18:00)
+    AfterHoursSinglePrice,
+    /// NXT 프리마켓(08:00~08:50)
+    PreMarket,
+    /// NXT 애프터마켓(15:30~20:00)
+    AfterMarket,
+}
+
+const fn hms(hour: u32, minute: u32, second: u32) -> u32 {
+    hour * 3600 + minute * 60 + second
+}
+
+/// KRX (시작 초, 종료 초, 시간대)
+const KRX_SCHEDULE: &[(u32, u32, Session)] = &[
+    (hms(8, 30, 0), hms(8, 40, 0), Session::PreMarketOffHours),
+    (hms(8, 40, 0), hms(9, 0, 0), Session::OpeningAuction),
+    (hms(9, 0, 0), hms(15, 20, 0), Session::Continuous),
+    (hms(15, 20, 0), hms(15, 30, 0), Session::ClosingAuction),
+    (hms(15, 30, 0), hms(16, 0, 0), Session::PostMarketOffHours),
+    (hms(16, 0, 0), hms(18, 0, 0), Session::AfterHoursSinglePrice),
+];
+
+/// NXT (시작 초, 종료 초, 시간대). 메인마켓은 09:00:30~15:20 접속매매
+const NXT_SCHEDULE: &[(u32, u32, Session)] = &[
+    (hms(8, 0, 0), hms(8, 50, 0), Session::PreMarket),
+    (hms(9, 0, 30), hms(15, 20, 0), Session::Continuous),
+    (hms(15, 30, 0), hms(20, 0, 0), Session::AfterMarket),
+];
+
+impl Session {
+    /// 시각 기준 KRX 시간대
+    pub fn at(time: &Time) -> Self {
+        Self::on(&Exchange::Krx, time)
+    }
+
+    /// 현재 KRX 시간대
+    pub fn now() -> Self {
+        Self::now_on(&Exchange::Krx)
+    }
+
+    /// 시각 기준 거래소별 시간대
+    /// SOR은 KRX 운영 시간대를 우선하고, KRX 장 운영 외에는 NXT 시간대를 따름
+    pub fn on(exchange: &Exchange, time: &Time) -> Self {
+        let time = time.inner();
+        Self::at_second(exchange, time.num_seconds_from_midnight())
+    }
+
+    /// 현재 거래소별 시간대
+    pub fn now_on(exchange: &Exchange) -> Self {
+        let now = chrono::Utc::now().with_timezone(&chrono_tz::Asia::Seoul);
+        Self::at_second(exchange, now.num_seconds_from_midnight())
+    }
+
+    fn at_second(exchange: &Exchange, second: u32) -> Self {
+        let find = |schedule: &[(u32, u32, Session)]| {
+            schedule
+                .iter()
+                .find(|(start, end, _)| *start <= second && second < *end)
+                .map(|(_, _, session)| *session)
+                .unwrap_or(Self::Closed)
+        };
+        match exchange {
+            Exchange::Krx => find(KRX_SCHEDULE),
+            Exchange::Nxt => find(NXT_SCHEDULE),
+            Exchange::Sor => match find(KRX_SCHEDULE) {
+                Self::Closed => find(NXT_SCHEDULE),
+                session => session,
+            },
+        }
+    }
+
+    /// 실시간 체결가의 시간구분코드로 판단하고, 장중(0)이면 체결 시각으로 판단
+    pub fn from_exec(body: &exec::Body) -> Self {
+        match body.time_class_code {
+            TimeClassCode::PreMarketPrediction => Self::OpeningAuction,
+            TimeClassCode::PostMarketPrediction => Self::ClosingAuction,
+            TimeClassCode::OutMarketSinglePricePredict => Self::AfterHoursSinglePrice,
+            TimeClassCode::InMarket | TimeClassCode::PostNinePmPredictionOrVi => {
+                Self::at(&body.exec_time)
+            }
+        }
+    }
+
+    /// 주문구분을 이 시간대에 접수할 수 있는지 여부
+    pub fn allows(&self, order_class: &OrderClass) -> bool {
+        use OrderClass::*;
+        match self {
+            Self::Closed => false,
+            Self::PreMarketOffHours => {
+                matches!(order_class, PreMarket | Limit | Market | ConditionalLimit)
+            }
+            Self::OpeningAuction | Self::ClosingAuction => {
+                matches!(order_class, Limit | Market | ConditionalLimit)
+            }
+            Self::Continuous => {
+                !matches!(order_class, PreMarket | PostMarket | OutMarketSinglePrice)
+            }
+            Self::PostMarketOffHours => matches!(order_class, PostMarket),
+            Self::AfterHoursSinglePrice => matches!(order_class, OutMarketSinglePrice),
+            Self::PreMarket | Self::AfterMarket => matches!(order_class, Limit),
+        }
+    }
+
+    /// 주문구분이 시간대에 맞지 않으면 Error::OrderClassNotInSession
+    pub fn validate(&self, order_class: &OrderClass) -> Result<(), Error> {
+        if self.allows(order_class) {
+            Ok(())
+        } else {
+            Err(Error::OrderClassNotInSession(
+                order_class.clone(),
+                format!("{:?}", self),
+            ))
+        }
+    }
+
+    /// 시간대에 맞는 기본 주문구분
+    /// limit이 true면 가격을 지정하는 주문, false면 가격을 지정하지 않는 주문(시장가/종가) 기준
+    pub fn order_class(&self, limit: bool) -> Option<OrderClass> {
+        match (self, limit) {
+            (Self::Closed, _) => None,
+            (Self::PreMarketOffHours, false) => Some(OrderClass::PreMarket),
+            (Self::PostMarketOffHours, false) => Some(OrderClass::PostMarket),
+            (Self::PostMarketOffHours, true) => None,
+            (Self::AfterHoursSinglePrice, true) => Some(OrderClass::OutMarketSinglePrice),
+            (Self::AfterHoursSinglePrice, false) => None,
+            (Self::PreMarket | Self::AfterMarket, false) => None,
+            (_, true) => Some(OrderClass::Limit),
+            (_, false) => Some(OrderClass::Market),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hms: &str) -> Time {
+        Time::parse(&format!("20240102{}", hms), "%Y%m%d%H%M%S").unwrap()
+    }
+
+    fn at(hms: &str) -> Session {
+        Session::at(&time(hms))
+    }
+
+    fn on(exchange: Exchange, hms: &str) -> Session {
+        Session::on(&exchange, &time(hms))
+    }
+
+    #[test]
+    fn krx_boundaries() {
+        let cases = [
+            ("082959", Session::Closed),
+            ("083000", Session::PreMarketOffHours),
+            ("083959", Session::PreMarketOffHours),
+            ("084000", Session::OpeningAuction),
+            ("085959", Session::OpeningAuction),
+            ("090000", Session::Continuous),
+            ("151959", Session::Continuous),
+            ("152000", Session::ClosingAuction),
+            ("152959", Session::ClosingAuction),
+            ("153000", Session::PostMarketOffHours),
+            ("155959", Session::PostMarketOffHours),
+            ("160000", Session::AfterHoursSinglePrice),
+            ("175959", Session::AfterHoursSinglePrice),
+            ("180000", Session::Closed),
+        ];
+        for (hms, session) in cases {
+            assert_eq!(at(hms), session, "{}", hms);
+        }
+    }
+
+    #[test]
+    fn nxt_boundaries() {
+        let cases = [
+            ("075959", Session::Closed),
+            ("080000", Session::PreMarket),
+            ("084959", Session::PreMarket),
+            ("085000", Session::Closed),
+            ("090029", Session::Closed),
+            ("090030", Session::Continuous),
+            ("151959", Session::Continuous),
+            ("152000", Session::Closed),
+            ("153000", Session::AfterMarket),
+            ("195959", Session::AfterMarket),
+            ("200000", Session::Closed),
+        ];
+        for (hms, session) in cases {
+            assert_eq!(on(Exchange::Nxt, hms), session, "{}", hms);
+        }
+    }
+
+    #[test]
+    fn sor_prefers_krx_and_falls_back_to_nxt() {
+        let cases = [
+            ("080000", Session::PreMarket),
+            ("083000", Session::PreMarketOffHours),
+            ("090000", Session::Continuous),
+            ("152000", Session::ClosingAuction),
+            ("153000", Session::PostMarketOffHours),
+            ("155959", Session::PostMarketOffHours),
+            ("160000", Session::AfterHoursSinglePrice),
+            ("180000", Session::AfterMarket),
+            ("200000", Session::Closed),
+        ];
+        for (hms, session) in cases {
+            assert_eq!(on(Exchange::Sor, hms), session, "{}", hms);
+        }
+    }
+
+    #[test]
+    fn order_classes_by_session() {
+        assert!(Session::Continuous.allows(&OrderClass::Market));
+        assert!(!Session::Continuous.allows(&OrderClass::PostMarket));
+        assert!(Session::PostMarketOffHours.allows(&OrderClass::PostMarket));
+        assert!(!Session::AfterMarket.allows(&OrderClass::Market));
+        assert!(Session::Closed.validate(&OrderClass::Limit).is_err());
+        assert_eq!(
+            Session::AfterHoursSinglePrice.order_class(true),
+            Some(OrderClass::OutMarketSinglePrice)
+        );
+        assert_eq!(
+            Session::PreMarketOffHours.order_class(false),
+            Some(OrderClass::PreMarket)
+        );
+        assert_eq!(Session::AfterMarket.order_class(false), None);
+    }
+}
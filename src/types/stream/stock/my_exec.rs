@@ -1,5 +1,7 @@
 use super::Header;
-use crate::types::{parse_bool, Aes256CbcDec, CorrectionClass, Direction, OrderClass, Time};
+use crate::types::{
+    parse_bool, AcceptClass, Aes256CbcDec, CorrectionClass, Direction, OrderClass, Time,
+};
 use crate::util::get_json_inner;
 use crate::{Error, BUF_SIZE};
 use aes::cipher::{block_padding::ZeroPadding, BlockDecryptMut, KeyIvInit};
@@ -61,6 +63,7 @@ impl MyExec {
                 tr_id: header_str[1].into(),
                 datetime: exec_time.clone(),
             };
+            #[allow(deprecated)]
             let body = Some(Body {
                 cust_id: splits[0].to_string(),
                 acnt_no: splits[1].to_string(),
@@ -75,8 +78,11 @@ impl MyExec {
                 exec_price: splits[10].parse()?,
                 exec_time,
                 is_refused: parse_bool(splits[12]),
-                is_executed: parse_bool(splits[13]),
+                // 1: 주문/정정/취소/거부, 2: 체결
+                is_executed: splits[13] == "2",
                 is_accepted: parse_bool(splits[14]),
+                // 알 수 없는 값은 접수로 처리
+                accept_class: splits[14].parse().unwrap_or_default(),
                 branch_no: splits[15].to_string(),
                 order_qty: splits[16].parse()?,
                 account_name: splits[17].to_string(),
@@ -117,7 +123,9 @@ pub struct Body {
     pub exec_time: Time,                   // STCK_CNTG_HOUR(주식 체결 시간)
     pub is_refused: bool,                  // RFUS_YN(거부여부)
     pub is_executed: bool,                 // CNTG_YN(체결여부)
-    pub is_accepted: bool,                 // ACPT_YN(접수여부)
+    #[deprecated(note = "use accept_class")]
+    pub is_accepted: bool, // ACPT_YN(접수여부)
+    pub accept_class: AcceptClass,         // ACPT_YN(접수여부)
     pub branch_no: String,                 // BRNC_NO(지점번호)
     pub order_qty: u64,                    // ODER_QTY(주문수량)
     pub account_name: String,              // ACNT_NAME(계좌명)
@@ -0,0 +1,374 @@
+use crate::types::{MarketId, Price, SecurityGroupId, Time};
+use crate::Error;
+
+/// 호가가격단위 2023-01-25 개편(유가증권/코스닥 단일화) 시행일
+const REFORM_DATE: (i32, u32, u32) = (2023, 1, 25);
+
+/// (구간 하한가, 호가단위)
+const STOCK: &[(u32, u32)] = &[
+    (0, 1),
+    (2_000, 5),
+    (5_000, 10),
+    (20_000, 50),
+    (50_000, 100),
+    (200_000, 500),
+    (500_000, 1_000),
+];
+const KOSPI_LEGACY: &[(u32, u32)] = &[
+    (0, 1),
+    (1_000, 5),
+    (5_000, 10),
+    (10_000, 50),
+    (50_000, 100),
+    (100_000, 500),
+    (500_000, 1_000),
+];
+const KOSDAQ_LEGACY: &[(u32, u32)] =
+    &[(0, 1), (1_000, 5), (5_000, 10), (10_000, 50), (50_000, 100)];
+const FUND: &[(u32, u32)] = &[(0, 1), (2_000, 5)];
+const FUND_LEGACY: &[(u32, u32)] = &[(0, 5)];
+const ELW: &[(u32, u32)] = &[(0, 5)];
+
+/// KRX 호가가격단위 표
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TickTable {
+    /// 주식(2023-01-25 이후 유가증권/코스닥/코넥스 공통)
+    Stock,
+    /// 유가증권 주식(2023-01-25 이전)
+    KospiLegacy,
+    /// 코스닥/코넥스 주식(2023-01-25 이전)
+    KosdaqLegacy,
+    /// ETF, ETN(2023-01-25 이후 2,000원 미만 1원, 이상 5원)
+    Fund,
+    /// ETF, ETN(2023-01-25 이전, 가격대와 무관하게 5원)
+    FundLegacy,
+    /// ELW(가격대와 무관하게 5원)
+    Elw,
+}
+
+/// 호가단위에 맞지 않는 가격의 보정 방향
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rounding {
+    /// 올림
+    Up,
+    /// 내림
+    Down,
+    /// 가까운 쪽(같으면 올림)
+    Nearest,
+}
+
+impl TickTable {
+    /// 시장ID, 증권그룹ID, 적용일자로 호가단위 표 선택
+    pub fn select(market: &MarketId, group: &SecurityGroupId, date: &Time) -> Self {
+        if let SecurityGroupId::Elw = group {
+            return Self::Elw;
+        }
+        let (y, m, d) = REFORM_DATE;
+        let reformed =
+            date.inner().date_naive() >= chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let fund = matches!(
+            group,
+            SecurityGroupId::Etf | SecurityGroupId::Etn | SecurityGroupId::ForeignEtf
+        ) || matches!(market, MarketId::Etf);
+        match (fund, reformed) {
+            (true, true) => Self::Fund,
+            (true, false) => Self::FundLegacy,
+            (false, true) => Self::Stock,
+            (false, false) => match market {
+                MarketId::Kosdaq | MarketId::Konex => Self::KosdaqLegacy,
+                _ => Self::KospiLegacy,
+            },
+        }
+    }
+
+    fn bands(&self) -> &'static [(u32, u32)] {
+        match self {
+            Self::Stock => STOCK,
+            Self::KospiLegacy => KOSPI_LEGACY,
+            Self::KosdaqLegacy => KOSDAQ_LEGACY,
+            Self::Fund => FUND,
+            Self::FundLegacy => FUND_LEGACY,
+            Self::Elw => ELW,
+        }
+    }
+
+    /// 가격이 속한 구간의 호가단위
+    pub fn tick_size(&self, price: Price) -> u32 {
+        self.bands()
+            .iter()
+            .rev()
+            .find(|(lower, _)| price.inner() >= *lower)
+            .map(|(_, tick)| *tick)
+            .unwrap()
+    }
+
+    /// 호가단위에 맞는 가격인지 여부(0원은 제외)
+    pub fn is_valid(&self, price: Price) -> bool {
+        price.inner() > 0 && self.round_down(price).inner() == price.inner()
+    }
+
+    /// 호가단위에 맞게 보정
+    pub fn round(&self, price: Price, rounding: Rounding) -> Price {
+        let p = price.inner();
+        let tick = self.tick_size(price);
+        let down = p - p % tick;
+        if down == p {
+            return price;
+        }
+        let up = down + tick;
+        Price::from(match rounding {
+            Rounding::Up => up,
+            Rounding::Down => down,
+            Rounding::Nearest => {
+                if p - down < up - p {
+                    down
+                } else {
+                    up
+                }
+            }
+        })
+    }
+
+    pub fn round_up(&self, price: Price) -> Price {
+        self.round(price, Rounding::Up)
+    }
+
+    pub fn round_down(&self, price: Price) -> Price {
+        self.round(price, Rounding::Down)
+    }
+
+    pub fn round_nearest(&self, price: Price) -> Price {
+        self.round(price, Rounding::Nearest)
+    }
+
+    /// 0원부터 가격까지의 호가 수
+    fn index(&self, price: u32) -> i64 {
+        let bands = self.bands();
+        let mut index = 0i64;
+        for (i, (lower, tick)) in bands.iter().enumerate() {
+            let upper = bands.get(i + 1).map(|(l, _)| *l).unwrap_or(u32::MAX);
+            if price < upper {
+                return index + ((price - lower) / tick) as i64;
+            }
+            index += ((upper - lower) / tick) as i64;
+        }
+        index
+    }
+
+    fn price_at(&self, index: i64) -> Option<u32> {
+        let bands = self.bands();
+        let mut remained = index;
+        for (i, (lower, tick)) in bands.iter().enumerate() {
+            let count = match bands.get(i + 1) {
+                Some((upper, _)) => ((upper - lower) / tick) as i64,
+                None => i64::MAX,
+            };
+            if remained < count {
+                return u32::try_from(*lower as i64 + remained * *tick as i64).ok();
+            }
+            remained -= count;
+        }
+        None
+    }
+
+    fn check(&self, price: Price) -> Result<(), Error> {
+        if self.is_valid(price) {
+            Ok(())
+        } else {
+            Err(Error::InvalidParameter(
+                "price",
+                format!("{} is not on a valid tick", price.inner()),
+            ))
+        }
+    }
+
+    /// 호가단위에 맞는 가격에서 ticks만큼(음수면 아래로) 이동한 가격
+    pub fn step(&self, price: Price, ticks: i64) -> Result<Price, Error> {
+        self.check(price)?;
+        match self.price_at(self.index(price.inner()) + ticks) {
+            Some(p) if p > 0 => Ok(Price::from(p)),
+            _ => Err(Error::InvalidParameter(
+                "ticks",
+                format!("{} ticks from {} is out of range", ticks, price.inner()),
+            )),
+        }
+    }
+
+    /// from에서 to까지의 호가 수(to가 낮으면 음수)
+    pub fn ticks_between(&self, from: Price, to: Price) -> Result<i64, Error> {
+        self.check(from)?;
+        self.check(to)?;
+        Ok(self.index(to.inner()) - self.index(from.inner()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> Time {
+        Time::parse(&format!("{}000000", s), "%Y%m%d%H%M%S").unwrap()
+    }
+
+    #[test]
+    fn select_by_reform_date() {
+        let (before, after) = (date("20230124"), date("20230125"));
+        let (kospi, kosdaq) = (MarketId::Stock, MarketId::Kosdaq);
+        let stock = SecurityGroupId::Stock;
+        assert_eq!(
+            TickTable::select(&kospi, &stock, &before),
+            TickTable::KospiLegacy
+        );
+        assert_eq!(
+            TickTable::select(&kosdaq, &stock, &before),
+            TickTable::KosdaqLegacy
+        );
+        assert_eq!(TickTable::select(&kospi, &stock, &after), TickTable::Stock);
+        assert_eq!(TickTable::select(&kosdaq, &stock, &after), TickTable::Stock);
+        for group in [
+            SecurityGroupId::Etf,
+            SecurityGroupId::Etn,
+            SecurityGroupId::ForeignEtf,
+        ] {
+            assert_eq!(TickTable::select(&kospi, &group, &after), TickTable::Fund);
+            assert_eq!(
+                TickTable::select(&kospi, &group, &before),
+                TickTable::FundLegacy
+            );
+        }
+        assert_eq!(
+            TickTable::select(&MarketId::Etf, &stock, &before),
+            TickTable::FundLegacy
+        );
+        assert_eq!(
+            TickTable::select(&MarketId::Etf, &stock, &after),
+            TickTable::Fund
+        );
+        let elw = SecurityGroupId::Elw;
+        assert_eq!(TickTable::select(&kospi, &elw, &before), TickTable::Elw);
+        assert_eq!(TickTable::select(&kospi, &elw, &after), TickTable::Elw);
+    }
+
+    #[test]
+    fn tick_size_at_band_boundaries() {
+        let cases: &[(TickTable, &[(u32, u32)])] = &[
+            (
+                TickTable::Stock,
+                &[
+                    (1_999, 1),
+                    (2_000, 5),
+                    (4_995, 5),
+                    (5_000, 10),
+                    (19_990, 10),
+                    (20_000, 50),
+                    (49_950, 50),
+                    (50_000, 100),
+                    (199_900, 100),
+                    (200_000, 500),
+                    (499_500, 500),
+                    (500_000, 1_000),
+                ],
+            ),
+            (
+                TickTable::KospiLegacy,
+                &[
+                    (999, 1),
+                    (1_000, 5),
+                    (4_995, 5),
+                    (5_000, 10),
+                    (9_990, 10),
+                    (10_000, 50),
+                    (49_950, 50),
+                    (50_000, 100),
+                    (99_900, 100),
+                    (100_000, 500),
+                    (499_500, 500),
+                    (500_000, 1_000),
+                ],
+            ),
+            (
+                TickTable::KosdaqLegacy,
+                &[
+                    (999, 1),
+                    (1_000, 5),
+                    (5_000, 10),
+                    (10_000, 50),
+                    (50_000, 100),
+                    (500_000, 100),
+                ],
+            ),
+            (
+                TickTable::Fund,
+                &[(1, 1), (1_999, 1), (2_000, 5), (50_000, 5), (500_000, 5)],
+            ),
+            (TickTable::FundLegacy, &[(1, 5), (2_000, 5), (500_000, 5)]),
+            (TickTable::Elw, &[(1, 5), (2_000, 5), (500_000, 5)]),
+        ];
+        for (table, bands) in cases {
+            for (price, tick) in bands.iter() {
+                assert_eq!(
+                    table.tick_size(Price::from(*price)),
+                    *tick,
+                    "{:?} {}",
+                    table,
+                    price
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn round_and_validate() {
+        let table = TickTable::Stock;
+        assert!(table.is_valid(Price::from(19_990)));
+        assert!(!table.is_valid(Price::from(20_010)));
+        assert!(!table.is_valid(Price::from(0)));
+        assert_eq!(table.round_up(Price::from(20_010)).inner(), 20_050);
+        assert_eq!(table.round_down(Price::from(20_010)).inner(), 20_000);
+        assert_eq!(table.round_nearest(Price::from(20_025)).inner(), 20_050);
+        assert_eq!(table.round_nearest(Price::from(20_024)).inner(), 20_000);
+        assert_eq!(
+            TickTable::Fund.round_down(Price::from(10_003)).inner(),
+            10_000
+        );
+    }
+
+    #[test]
+    fn step_across_bands() {
+        let table = TickTable::Stock;
+        assert_eq!(table.step(Price::from(1_999), 1).unwrap().inner(), 2_000);
+        assert_eq!(table.step(Price::from(2_000), 1).unwrap().inner(), 2_005);
+        assert_eq!(table.step(Price::from(2_000), -1).unwrap().inner(), 1_999);
+        assert_eq!(table.step(Price::from(49_950), 2).unwrap().inner(), 50_100);
+        assert!(table.step(Price::from(1), -1).is_err());
+        assert!(table.step(Price::from(2_001), 1).is_err());
+    }
+
+    #[test]
+    fn ticks_between_bands() {
+        let table = TickTable::Stock;
+        let between = |from: u32, to: u32| {
+            table
+                .ticks_between(Price::from(from), Price::from(to))
+                .unwrap()
+        };
+        assert_eq!(between(1_999, 2_000), 1);
+        assert_eq!(between(2_000, 1_999), -1);
+        assert_eq!(between(1_990, 2_010), 12);
+        assert_eq!(between(49_900, 50_200), 4);
+        assert_eq!(between(20_000, 20_000), 0);
+        assert_eq!(
+            TickTable::Fund
+                .ticks_between(Price::from(1_990), Price::from(2_050))
+                .unwrap(),
+            20
+        );
+        assert!(table
+            .ticks_between(Price::from(2_001), Price::from(2_005))
+            .is_err());
+        for (from, to) in [(1_500, 250_000), (4_990, 5_010), (199_900, 600_000)] {
+            let ticks = between(from, to);
+            assert_eq!(table.step(Price::from(from), ticks).unwrap().inner(), to);
+        }
+    }
+}